@@ -0,0 +1,162 @@
+//! Algorithms that operate on the builtin types.
+use crate::{Number, Rect, Vec2};
+
+/// A horizontal segment of the skyline, `y` is the lowest free coordinate along it.
+#[derive(Copy, Clone, Debug)]
+struct Segment<N: Number> {
+	x: N,
+	y: N,
+	width: N,
+}
+
+/// Packs rectangles of the given sizes into the container using a skyline packer.
+/// Every rectangle is placed at the position where its bottom edge ends up the highest (bottom-left heuristic).
+///
+/// # Arguments
+///
+/// * `container`: The area the rectangles are packed into.
+/// * `sizes`: The sizes of the rectangles to pack, in order.
+///
+/// returns: Vec<Option<Rect<N>>> The placed rectangle for each size, or None if it did not fit.
+///
+/// # Examples
+///
+/// ```
+/// use mathie::{Rect, Vec2};
+/// use mathie::algo::pack;
+/// let placed = pack(Rect::new([0, 0], [4, 4]), &[Vec2::new(2, 2), Vec2::new(2, 2), Vec2::new(5, 1)]);
+/// assert_eq!(placed, vec![Some(Rect::new([0, 0], [2, 2])), Some(Rect::new([2, 0], [2, 2])), None]);
+/// ```
+pub fn pack<N: Number>(container: Rect<N>, sizes: &[Vec2<N>]) -> Vec<Option<Rect<N>>> {
+	let mut skyline = vec![Segment {
+		x: container.left(),
+		y: container.top(),
+		width: container.size().x(),
+	}];
+
+	sizes
+		.iter()
+		.map(|size| {
+			let mut best: Option<(usize, Rect<N>)> = None;
+			for i in 0..skyline.len() {
+				if let Some(rect) = fit(&skyline, i, *size, container) {
+					let better = match best {
+						None => true,
+						Some((_, best)) => rect.bottom() < best.bottom() || (rect.bottom() == best.bottom() && rect.left() < best.left()),
+					};
+					if better {
+						best = Some((i, rect));
+					}
+				}
+			}
+
+			let (i, rect) = best?;
+			if rect.size().x() > N::zero() {
+				place(&mut skyline, i, rect);
+			}
+			Some(rect)
+		})
+		.collect()
+}
+
+/// Tries to place a rectangle of `size` on the skyline starting at the segment `i`.
+fn fit<N: Number>(skyline: &[Segment<N>], i: usize, size: Vec2<N>, container: Rect<N>) -> Option<Rect<N>> {
+	let x = skyline[i].x;
+	let mut y = skyline[i].y;
+	let mut covered = N::zero();
+	let mut j = i;
+	while covered < size.x() {
+		let segment = skyline.get(j)?;
+		if segment.y > y {
+			y = segment.y;
+		}
+		covered = covered + segment.width;
+		j += 1;
+	}
+
+	let rect = Rect::new([x, y], size);
+	if container.contains_rect(rect) {
+		Some(rect)
+	} else {
+		None
+	}
+}
+
+/// Raises the skyline under the placed rectangle.
+fn place<N: Number>(skyline: &mut Vec<Segment<N>>, i: usize, rect: Rect<N>) {
+	let right = rect.right();
+	skyline.insert(i, Segment {
+		x: rect.left(),
+		y: rect.bottom(),
+		width: rect.size().x(),
+	});
+
+	// Cut away the segments that are now covered by the new one.
+	let j = i + 1;
+	while j < skyline.len() && skyline[j].x < right {
+		let segment = &mut skyline[j];
+		let segment_right = segment.x + segment.width;
+		if segment_right <= right {
+			skyline.remove(j);
+		} else {
+			segment.width = segment_right - right;
+			segment.x = right;
+			break;
+		}
+	}
+
+	// Merge neighbours which are on the same height.
+	let mut j = 0;
+	while j + 1 < skyline.len() {
+		if skyline[j].y == skyline[j + 1].y {
+			let next = skyline.remove(j + 1);
+			skyline[j].width = skyline[j].width + next.width;
+		} else {
+			j += 1;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn overlaps(a: Rect<f32>, b: Rect<f32>) -> bool {
+		a.left() < b.right() && b.left() < a.right() && a.top() < b.bottom() && b.top() < a.bottom()
+	}
+
+	#[test]
+	fn pack_no_overlap() {
+		let container = Rect::new([0.0f32, 0.0], [10.0, 10.0]);
+		let sizes = [
+			Vec2::new(4.0, 3.0),
+			Vec2::new(3.0, 5.0),
+			Vec2::new(2.0, 2.0),
+			Vec2::new(5.0, 1.0),
+			Vec2::new(1.0, 4.0),
+			Vec2::new(3.0, 3.0),
+			Vec2::new(6.0, 2.0),
+			Vec2::new(11.0, 1.0),
+		];
+		let placed = pack(container, &sizes);
+		assert_eq!(placed.len(), sizes.len());
+		assert_eq!(placed[7], None);
+
+		let placed: Vec<Rect<f32>> = placed.into_iter().flatten().collect();
+		assert_eq!(placed.len(), 7);
+		for (i, rect) in placed.iter().enumerate() {
+			assert!(container.contains_rect(*rect));
+			for other in &placed[i + 1..] {
+				assert!(!overlaps(*rect, *other), "{rect:?} overlaps {other:?}");
+			}
+		}
+	}
+
+	#[test]
+	fn pack_full() {
+		let container = Rect::new([0u32, 0], [4, 4]);
+		let placed = pack(container, &[Vec2::split(2); 5]);
+		assert_eq!(placed[..4].iter().flatten().count(), 4);
+		assert_eq!(placed[4], None);
+	}
+}
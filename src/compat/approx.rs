@@ -0,0 +1,95 @@
+//! Implements the approx traits so float vectors and rectangles can be compared with a tolerance.
+//! Every value is compared separately and the default epsilons are the ones of the number type.
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use crate::number::Number;
+use crate::types::rect::Rect;
+use crate::types::vec2::Vec2;
+
+impl<T: Number + AbsDiffEq> AbsDiffEq for Vec2<T> where T::Epsilon: Copy {
+	type Epsilon = T::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		T::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		T::abs_diff_eq(&self.x(), &other.x(), epsilon) && T::abs_diff_eq(&self.y(), &other.y(), epsilon)
+	}
+}
+
+impl<T: Number + RelativeEq> RelativeEq for Vec2<T> where T::Epsilon: Copy {
+	fn default_max_relative() -> Self::Epsilon {
+		T::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		T::relative_eq(&self.x(), &other.x(), epsilon, max_relative) && T::relative_eq(&self.y(), &other.y(), epsilon, max_relative)
+	}
+}
+
+impl<T: Number + UlpsEq> UlpsEq for Vec2<T> where T::Epsilon: Copy {
+	fn default_max_ulps() -> u32 {
+		T::default_max_ulps()
+	}
+
+	fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+		T::ulps_eq(&self.x(), &other.x(), epsilon, max_ulps) && T::ulps_eq(&self.y(), &other.y(), epsilon, max_ulps)
+	}
+}
+
+impl<T: Number + AbsDiffEq> AbsDiffEq for Rect<T> where T::Epsilon: Copy {
+	type Epsilon = T::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		T::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.origin.abs_diff_eq(&other.origin, epsilon) && self.size.abs_diff_eq(&other.size, epsilon)
+	}
+}
+
+impl<T: Number + RelativeEq> RelativeEq for Rect<T> where T::Epsilon: Copy {
+	fn default_max_relative() -> Self::Epsilon {
+		T::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.origin.relative_eq(&other.origin, epsilon, max_relative) && self.size.relative_eq(&other.size, epsilon, max_relative)
+	}
+}
+
+impl<T: Number + UlpsEq> UlpsEq for Rect<T> where T::Epsilon: Copy {
+	fn default_max_ulps() -> u32 {
+		T::default_max_ulps()
+	}
+
+	fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+		self.origin.ulps_eq(&other.origin, epsilon, max_ulps) && self.size.ulps_eq(&other.size, epsilon, max_ulps)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+	use super::*;
+
+	#[test]
+	fn vec2() {
+		let rotated = Vec2::new(1.0, 0.0).rotate(std::f64::consts::FRAC_PI_2);
+		assert_ne!(rotated, Vec2::new(0.0, 1.0));
+		assert_abs_diff_eq!(rotated, Vec2::new(0.0, 1.0));
+		assert_relative_eq!(Vec2::new(0.1 + 0.2, 1e10), Vec2::new(0.3, 1e10 + 1e-3), max_relative = 1e-12);
+		assert_ulps_eq!(Vec2::new(0.1f32 + 0.2, 1.0), Vec2::new(0.3, 1.0));
+		assert_relative_ne!(Vec2::new(0.3, 1.0), Vec2::new(0.3, 1.001));
+		assert_eq!(Vec2::<f64>::default_epsilon(), f64::EPSILON);
+	}
+
+	#[test]
+	fn rect() {
+		let rect = Rect::new([0.1 + 0.2, 0.0], [1.0, 1.0]);
+		assert_relative_eq!(rect, Rect::new([0.3, 0.0], [1.0, 1.0]));
+		assert_abs_diff_eq!(rect, Rect::new([0.3, 0.1], [1.0, 1.0]), epsilon = 0.2);
+		assert_relative_ne!(rect, Rect::new([0.3, 0.0], [1.0, 2.0]));
+	}
+}
@@ -0,0 +1,37 @@
+//! Implements the bytemuck traits so slices of mathie types can be cast to raw bytes or numbers.
+use bytemuck::{Pod, Zeroable};
+use crate::number::Number;
+use crate::types::rect::Rect;
+use crate::types::vec2::Vec2;
+
+// SAFETY: Vec2 is `#[repr(C)]` over `[T; 2]`, which has no padding and is valid for any bit pattern when T is.
+unsafe impl<T: Number + Zeroable> Zeroable for Vec2<T> {}
+unsafe impl<T: Number + Pod> Pod for Vec2<T> {}
+
+// SAFETY: Rect is `#[repr(C)]` over two Vec2<T> of the same alignment, so it has no padding either.
+unsafe impl<T: Number + Zeroable> Zeroable for Rect<T> {}
+unsafe impl<T: Number + Pod> Pod for Rect<T> {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn cast_vec2_slice() {
+		let vecs = [Vec2::new(1.0f32, 2.0), Vec2::new(3.0, 4.0)];
+		let floats: &[f32] = bytemuck::cast_slice(&vecs);
+		assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0]);
+
+		let back: &[Vec2<f32>] = bytemuck::cast_slice(floats);
+		assert_eq!(back, &vecs);
+	}
+
+	#[test]
+	fn cast_rect_slice() {
+		let rects = [Rect::new([1u32, 2], [3, 4])];
+		let values: &[u32] = bytemuck::cast_slice(&rects);
+		assert_eq!(values, &[1, 2, 3, 4]);
+		assert_eq!(bytemuck::cast_slice::<u32, u8>(values).len(), 16);
+		assert_eq!(Rect::<u32>::zeroed(), Rect::zero());
+	}
+}
@@ -1,5 +1,5 @@
 //! Implements From for euclid types for inter-compatibility.
-use euclid::{Point2D, Vector2D};
+use euclid::{Point2D, Size2D, Vector2D};
 use crate::number::Number;
 use crate::types::rect::Rect;
 use crate::types::vec2::Vec2;
@@ -26,4 +26,56 @@ impl<T: Number, U> From<euclid::Box2D<T, U>> for Rect<T> {
 	fn from(b: euclid::Box2D<T, U>) -> Self {
 		Rect::new_min_max(b.min.to_array(), b.max.to_array())
 	}
-}
\ No newline at end of file
+}
+
+impl<T: Number, U> From<Vec2<T>> for Vector2D<T, U> {
+	fn from(vec: Vec2<T>) -> Self {
+		Vector2D::new(vec.x(), vec.y())
+	}
+}
+
+impl<T: Number, U> From<Vec2<T>> for Point2D<T, U> {
+	fn from(vec: Vec2<T>) -> Self {
+		Point2D::new(vec.x(), vec.y())
+	}
+}
+
+impl<T: Number, U> From<Rect<T>> for euclid::Rect<T, U> {
+	fn from(rect: Rect<T>) -> Self {
+		euclid::Rect::new(rect.origin().into(), Size2D::new(rect.size().x(), rect.size().y()))
+	}
+}
+
+impl<T: Number, U> From<Rect<T>> for euclid::Box2D<T, U> {
+	fn from(rect: Rect<T>) -> Self {
+		euclid::Box2D::new(rect.min().into(), rect.max().into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use euclid::UnknownUnit;
+	use super::*;
+
+	#[test]
+	fn vec2_round_trip() {
+		let vec = Vec2::new(1.5f32, -2.0);
+		let vector: Vector2D<f32, UnknownUnit> = vec.into();
+		let point: Point2D<f32, UnknownUnit> = vec.into();
+		assert_eq!(vector, Vector2D::new(1.5, -2.0));
+		assert_eq!(point, Point2D::new(1.5, -2.0));
+		assert_eq!(Vec2::from(vector), vec);
+		assert_eq!(Vec2::from(point), vec);
+	}
+
+	#[test]
+	fn rect_round_trip() {
+		let rect = Rect::new([1, 2], [3, 4]);
+		let euclid_rect: euclid::Rect<i32, UnknownUnit> = rect.into();
+		let euclid_box: euclid::Box2D<i32, UnknownUnit> = rect.into();
+		assert_eq!(euclid_rect, euclid::Rect::new(Point2D::new(1, 2), Size2D::new(3, 4)));
+		assert_eq!(euclid_box, euclid::Box2D::new(Point2D::new(1, 2), Point2D::new(4, 6)));
+		assert_eq!(Rect::from(euclid_rect), rect);
+		assert_eq!(Rect::from(euclid_box), rect);
+	}
+}
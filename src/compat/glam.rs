@@ -0,0 +1,80 @@
+//! Implements From between glam types and mathie types for inter-compatibility.
+use glam::{DVec2, IVec2, UVec2};
+use crate::types::vec2::Vec2;
+
+impl From<glam::Vec2> for Vec2<f32> {
+	fn from(vec: glam::Vec2) -> Self {
+		Vec2::new(vec.x, vec.y)
+	}
+}
+
+impl From<Vec2<f32>> for glam::Vec2 {
+	fn from(vec: Vec2<f32>) -> Self {
+		glam::Vec2::new(vec.x(), vec.y())
+	}
+}
+
+impl From<DVec2> for Vec2<f64> {
+	fn from(vec: DVec2) -> Self {
+		Vec2::new(vec.x, vec.y)
+	}
+}
+
+impl From<Vec2<f64>> for DVec2 {
+	fn from(vec: Vec2<f64>) -> Self {
+		DVec2::new(vec.x(), vec.y())
+	}
+}
+
+impl From<IVec2> for Vec2<i32> {
+	fn from(vec: IVec2) -> Self {
+		Vec2::new(vec.x, vec.y)
+	}
+}
+
+impl From<Vec2<i32>> for IVec2 {
+	fn from(vec: Vec2<i32>) -> Self {
+		IVec2::new(vec.x(), vec.y())
+	}
+}
+
+impl From<UVec2> for Vec2<u32> {
+	fn from(vec: UVec2) -> Self {
+		Vec2::new(vec.x, vec.y)
+	}
+}
+
+impl From<Vec2<u32>> for UVec2 {
+	fn from(vec: Vec2<u32>) -> Self {
+		UVec2::new(vec.x(), vec.y())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn vec2_round_trip() {
+		let vec = glam::Vec2::new(1.5, -2.0);
+		let mathie: Vec2<f32> = vec.into();
+		assert_eq!(mathie, Vec2::new(1.5, -2.0));
+		assert_eq!(glam::Vec2::from(mathie), vec);
+
+		let vec = DVec2::new(0.25, 8.0);
+		assert_eq!(DVec2::from(Vec2::from(vec)), vec);
+	}
+
+	#[test]
+	fn integer_round_trip() {
+		let vec = IVec2::new(-3, 4);
+		let mathie: Vec2<i32> = vec.into();
+		assert_eq!(mathie, Vec2::new(-3, 4));
+		assert_eq!(IVec2::from(mathie), vec);
+
+		let vec = UVec2::new(3, u32::MAX);
+		let mathie: Vec2<u32> = vec.into();
+		assert_eq!(mathie, Vec2::new(3, u32::MAX));
+		assert_eq!(UVec2::from(mathie), vec);
+	}
+}
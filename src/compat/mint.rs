@@ -0,0 +1,64 @@
+//! Implements From between mint types and mathie types, which allows interop with every library that supports mint.
+use mint::{IntoMint, Point2, Vector2};
+use crate::number::Number;
+use crate::types::vec2::Vec2;
+
+impl<T: Number> From<Vector2<T>> for Vec2<T> {
+	fn from(vec: Vector2<T>) -> Self {
+		Vec2::new(vec.x, vec.y)
+	}
+}
+
+impl<T: Number> From<Point2<T>> for Vec2<T> {
+	fn from(point: Point2<T>) -> Self {
+		Vec2::new(point.x, point.y)
+	}
+}
+
+impl<T: Number> From<Vec2<T>> for Vector2<T> {
+	fn from(vec: Vec2<T>) -> Self {
+		Vector2 {
+			x: vec.x(),
+			y: vec.y(),
+		}
+	}
+}
+
+impl<T: Number> From<Vec2<T>> for Point2<T> {
+	fn from(vec: Vec2<T>) -> Self {
+		Point2 {
+			x: vec.x(),
+			y: vec.y(),
+		}
+	}
+}
+
+impl<T: Number> IntoMint for Vec2<T> {
+	type MintType = Vector2<T>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn to_mint<V: IntoMint>(value: V) -> V::MintType {
+		value.into()
+	}
+
+	#[test]
+	fn vector_round_trip() {
+		let vec = Vector2 { x: 1.5f32, y: -2.0 };
+		let mathie: Vec2<f32> = vec.into();
+		assert_eq!(mathie, Vec2::new(1.5, -2.0));
+		assert_eq!(Vector2::from(mathie), vec);
+		assert_eq!(to_mint(mathie), vec);
+	}
+
+	#[test]
+	fn point_round_trip() {
+		let point = Point2 { x: 3i32, y: 4 };
+		let mathie: Vec2<i32> = point.into();
+		assert_eq!(mathie, Vec2::new(3, 4));
+		assert_eq!(Point2::from(mathie), point);
+	}
+}
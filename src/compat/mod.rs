@@ -2,3 +2,15 @@
 pub mod euclid;
 #[cfg(feature = "serde")]
 pub mod serde;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck;
+#[cfg(feature = "approx")]
+pub mod approx;
+#[cfg(feature = "rand")]
+pub mod rand;
+#[cfg(feature = "glam")]
+pub mod glam;
+#[cfg(feature = "mint")]
+pub mod mint;
\ No newline at end of file
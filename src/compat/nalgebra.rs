@@ -0,0 +1,72 @@
+//! Implements From between nalgebra types and mathie types for inter-compatibility.
+use nalgebra::{Point2, Scalar, Vector2};
+use crate::number::Number;
+use crate::types::rect::Rect;
+use crate::types::vec2::Vec2;
+
+impl<T: Number + Scalar> From<Vector2<T>> for Vec2<T> {
+	fn from(vec: Vector2<T>) -> Self {
+		Vec2::new(vec.x, vec.y)
+	}
+}
+
+impl<T: Number + Scalar> From<Point2<T>> for Vec2<T> {
+	fn from(point: Point2<T>) -> Self {
+		Vec2::new(point.x, point.y)
+	}
+}
+
+impl<T: Number + Scalar> From<Vec2<T>> for Vector2<T> {
+	fn from(vec: Vec2<T>) -> Self {
+		Vector2::new(vec.x(), vec.y())
+	}
+}
+
+impl<T: Number + Scalar> From<Vec2<T>> for Point2<T> {
+	fn from(vec: Vec2<T>) -> Self {
+		Point2::new(vec.x(), vec.y())
+	}
+}
+
+/// Creates the rectangle from an axis-aligned bounding box given as its `(min, max)` corners.
+impl<T: Number + Scalar> From<(Point2<T>, Point2<T>)> for Rect<T> {
+	fn from((min, max): (Point2<T>, Point2<T>)) -> Self {
+		Rect::new_min_max(Vec2::from(min), Vec2::from(max))
+	}
+}
+
+/// Converts the rectangle into an axis-aligned bounding box given as its `(min, max)` corners.
+impl<T: Number + Scalar> From<Rect<T>> for (Point2<T>, Point2<T>) {
+	fn from(rect: Rect<T>) -> Self {
+		(rect.min().into(), rect.max().into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn vector_round_trip() {
+		let vec = Vector2::new(1.5f32, -2.0);
+		let mathie: Vec2<f32> = vec.into();
+		assert_eq!(mathie, Vec2::new(1.5, -2.0));
+		assert_eq!(Vector2::from(mathie), vec);
+	}
+
+	#[test]
+	fn point_round_trip() {
+		let point = Point2::new(3i32, 4);
+		let mathie: Vec2<i32> = point.into();
+		assert_eq!(mathie, Vec2::new(3, 4));
+		assert_eq!(Point2::from(mathie), point);
+	}
+
+	#[test]
+	fn aabb_round_trip() {
+		let aabb = (Point2::new(1.0f64, 2.0), Point2::new(4.0, 6.0));
+		let rect: Rect<f64> = aabb.into();
+		assert_eq!(rect, Rect::new([1.0, 2.0], [3.0, 4.0]));
+		assert_eq!(<(Point2<f64>, Point2<f64>)>::from(rect), aabb);
+	}
+}
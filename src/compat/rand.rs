@@ -0,0 +1,85 @@
+//! Implements random sampling of mathie types with rand.
+use num_traits::Float;
+use rand::distr::{Distribution, StandardUniform};
+use rand::Rng;
+use crate::number::Number;
+use crate::types::rect::Rect;
+use crate::types::vec2::Vec2;
+
+/// Samples both values from [StandardUniform], for floats this is uniform in `[0, 1)` on both axes.
+impl<N: Number> Distribution<Vec2<N>> for StandardUniform where StandardUniform: Distribution<N> {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2<N> {
+		Vec2::new(rng.random(), rng.random())
+	}
+}
+
+impl<F: Number + Float> Vec2<F> where StandardUniform: Distribution<F> {
+	/// Samples a uniformly distributed point inside of the `rect`.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::new([10.0, 10.0], [5.0, 2.0]);
+	/// let point = Vec2::random_in_rect(rect, &mut rand::rng());
+	/// assert!(rect.contains_pos(point));
+	/// ```
+	pub fn random_in_rect<R: Rng + ?Sized>(rect: Rect<F>, rng: &mut R) -> Vec2<F> {
+		rect.point_at_uv(rng.random::<Vec2<F>>())
+	}
+
+	/// Samples a unit vector pointing in a uniformly distributed direction.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// let direction = Vec2::<f64>::random_unit(&mut rand::rng());
+	/// assert!((direction.hypot() - 1.0).abs() < 1e-10);
+	/// ```
+	pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Vec2<F> {
+		let tau = F::from_f64(std::f64::consts::TAU).unwrap();
+		Vec2::from_angle(rng.random::<F>() * tau)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::rngs::StdRng;
+	use rand::SeedableRng;
+	use super::*;
+
+	#[test]
+	fn standard_range() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..1000 {
+			let vec: Vec2<f32> = rng.random();
+			assert!(vec.all(|v| (0.0..1.0).contains(&v)));
+		}
+	}
+
+	#[test]
+	fn random_in_rect() {
+		let mut rng = StdRng::seed_from_u64(1);
+		let rect = Rect::new([-4.0, 2.0], [8.0, 0.5]);
+		let mut bounds = Rect::from_center(rect.center(), [0.0, 0.0]);
+		for _ in 0..1000 {
+			let point = Vec2::random_in_rect(rect, &mut rng);
+			assert!(rect.contains_pos(point));
+			bounds = bounds.grow_to_contain_circle(point, 0.0);
+		}
+
+		// The samples should spread over most of the rectangle.
+		assert!(bounds.area() > rect.area() * 0.9);
+	}
+
+	#[test]
+	fn random_unit() {
+		let mut rng = StdRng::seed_from_u64(2);
+		let mut sum = Vec2::zero();
+		for _ in 0..1000 {
+			let direction = Vec2::<f64>::random_unit(&mut rng);
+			assert!((direction.hypot() - 1.0).abs() < 1e-10);
+			sum += direction;
+		}
+
+		// Uniform directions cancel each other out.
+		assert!((sum / 1000.0).hypot() < 0.1);
+	}
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
 use crate::{Number, Rect, Vec2};
 
 impl<T: Number + Serialize> Serialize for Rect<T> {
@@ -29,3 +30,203 @@ impl<'de, T: Number + Deserialize<'de>> Deserialize<'de> for Vec2<T> {
 		Ok(Vec2([x, y]))
 	}
 }
+
+/// Wraps a [Vec2] or [Rect] so non-finite floats are serialized as the strings `"inf"`, `"-inf"` and `"nan"`.
+/// This allows them to round-trip through formats like JSON which can not represent them as numbers.
+/// Finite values keep the default numeric encoding.
+///
+/// # Examples
+///
+/// ```
+/// use mathie::{Lossless, Rect};
+/// let rect = Lossless(Rect::new([0.0, 0.0], [f64::INFINITY, 1.0]));
+/// let json = serde_json::to_string(&rect).unwrap();
+/// assert_eq!(json, r#"[[0.0,0.0],["inf",1.0]]"#);
+/// assert_eq!(serde_json::from_str::<Lossless<Rect<f64>>>(&json).unwrap(), rect);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Lossless<V>(pub V);
+
+/// A single number which encodes non-finite values as tagged strings.
+struct LosslessNumber<T>(T);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LosslessRepr<T> {
+	Number(T),
+	Tagged(String),
+}
+
+impl<T: Number + Serialize> Serialize for LosslessNumber<T> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+		match self.0.to_f64() {
+			Some(v) if v.is_nan() => serializer.serialize_str("nan"),
+			Some(v) if v == f64::INFINITY => serializer.serialize_str("inf"),
+			Some(v) if v == f64::NEG_INFINITY => serializer.serialize_str("-inf"),
+			_ => self.0.serialize(serializer),
+		}
+	}
+}
+
+impl<'de, T: Number + Deserialize<'de>> Deserialize<'de> for LosslessNumber<T> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+		let value = match LosslessRepr::deserialize(deserializer)? {
+			LosslessRepr::Number(value) => return Ok(LosslessNumber(value)),
+			LosslessRepr::Tagged(tag) => match tag.as_str() {
+				"nan" => f64::NAN,
+				"inf" => f64::INFINITY,
+				"-inf" => f64::NEG_INFINITY,
+				_ => return Err(D::Error::custom(format!("unknown number tag \"{tag}\""))),
+			},
+		};
+
+		T::from_f64(value)
+			.map(LosslessNumber)
+			.ok_or_else(|| D::Error::custom(format!("\"{value}\" can not be represented by the number type")))
+	}
+}
+
+impl<T: Number + Serialize> Serialize for Lossless<Rect<T>> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+		(Lossless(self.0.origin), Lossless(self.0.size)).serialize(serializer)
+	}
+}
+
+impl<T: Number + Serialize> Serialize for Lossless<Vec2<T>> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+		(LosslessNumber(self.0.x()), LosslessNumber(self.0.y())).serialize(serializer)
+	}
+}
+
+impl<'de, T: Number + Deserialize<'de>> Deserialize<'de> for Lossless<Rect<T>> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+		let (Lossless(origin), Lossless(size)) = Deserialize::deserialize(deserializer)?;
+		Ok(Lossless(Rect {
+			origin,
+			size
+		}))
+	}
+}
+
+impl<'de, T: Number + Deserialize<'de>> Deserialize<'de> for Lossless<Vec2<T>> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+		let (LosslessNumber(x), LosslessNumber(y)) = Deserialize::deserialize(deserializer)?;
+		Ok(Lossless(Vec2([x, y])))
+	}
+}
+
+/// Wraps a [Vec2] or [Rect] so it is serialized with named fields instead of as a tuple,
+/// `{"x": .., "y": ..}` for a [Vec2] and `{"x": .., "y": .., "w": .., "h": ..}` for a [Rect].
+/// This is more readable in hand written formats like config files.
+///
+/// # Examples
+///
+/// ```
+/// use mathie::{Named, Rect};
+/// let rect = Named(Rect::new([0, 0], [640, 480]));
+/// let json = serde_json::to_string(&rect).unwrap();
+/// assert_eq!(json, r#"{"x":0,"y":0,"w":640,"h":480}"#);
+/// assert_eq!(serde_json::from_str::<Named<Rect<i32>>>(&json).unwrap(), rect);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Named<V>(pub V);
+
+#[derive(Serialize, Deserialize)]
+struct NamedVec2<T> {
+	x: T,
+	y: T,
+}
+
+#[derive(Serialize, Deserialize)]
+struct NamedRect<T> {
+	x: T,
+	y: T,
+	w: T,
+	h: T,
+}
+
+impl<T: Number + Serialize> Serialize for Named<Rect<T>> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+		NamedRect {
+			x: self.0.origin.x(),
+			y: self.0.origin.y(),
+			w: self.0.size.x(),
+			h: self.0.size.y(),
+		}.serialize(serializer)
+	}
+}
+
+impl<T: Number + Serialize> Serialize for Named<Vec2<T>> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+		NamedVec2 {
+			x: self.0.x(),
+			y: self.0.y(),
+		}.serialize(serializer)
+	}
+}
+
+impl<'de, T: Number + Deserialize<'de>> Deserialize<'de> for Named<Rect<T>> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+		let NamedRect { x, y, w, h } = Deserialize::deserialize(deserializer)?;
+		Ok(Named(Rect::new([x, y], [w, h])))
+	}
+}
+
+impl<'de, T: Number + Deserialize<'de>> Deserialize<'de> for Named<Vec2<T>> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+		let NamedVec2 { x, y } = Deserialize::deserialize(deserializer)?;
+		Ok(Named(Vec2([x, y])))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn lossless_infinite_rect() {
+		let rect = Rect::new([f64::NEG_INFINITY, 0.0], [f64::INFINITY, 2.5]);
+		let json = serde_json::to_string(&Lossless(rect)).unwrap();
+		assert_eq!(json, r#"[["-inf",0.0],["inf",2.5]]"#);
+
+		let Lossless(out) = serde_json::from_str::<Lossless<Rect<f64>>>(&json).unwrap();
+		assert_eq!(out, rect);
+		assert_eq!(out.size().x(), f64::INFINITY);
+	}
+
+	#[test]
+	fn lossless_nan_vec() {
+		let json = serde_json::to_string(&Lossless(Vec2::new(f32::NAN, 1.0))).unwrap();
+		assert_eq!(json, r#"["nan",1.0]"#);
+
+		let Lossless(out) = serde_json::from_str::<Lossless<Vec2<f32>>>(&json).unwrap();
+		assert!(out.x().is_nan());
+		assert_eq!(out.y(), 1.0);
+	}
+
+	#[test]
+	fn lossless_finite_matches_default() {
+		let rect = Rect::new([1, 2], [3, 4]);
+		assert_eq!(serde_json::to_string(&Lossless(rect)).unwrap(), serde_json::to_string(&rect).unwrap());
+		assert!(serde_json::from_str::<Lossless<Vec2<f32>>>(r#"["infinity",1.0]"#).is_err());
+	}
+
+	#[test]
+	fn named_round_trip() {
+		let rect = Rect::new([1.5, 2.0], [3.0, 4.0]);
+		let json = serde_json::to_string(&Named(rect)).unwrap();
+		assert_eq!(json, r#"{"x":1.5,"y":2.0,"w":3.0,"h":4.0}"#);
+		assert_eq!(serde_json::from_str::<Named<Rect<f64>>>(&json).unwrap(), Named(rect));
+
+		let json = serde_json::to_string(&Named(Vec2::new(1, 2))).unwrap();
+		assert_eq!(json, r#"{"x":1,"y":2}"#);
+		assert_eq!(serde_json::from_str::<Named<Vec2<i32>>>(r#"{"y":2,"x":1}"#).unwrap(), Named(Vec2::new(1, 2)));
+	}
+
+	#[test]
+	fn named_leaves_default_alone() {
+		let rect = Rect::new([1, 2], [3, 4]);
+		assert_eq!(serde_json::to_string(&rect).unwrap(), "[[1,2],[3,4]]");
+		assert_eq!(serde_json::from_str::<Rect<i32>>("[[1,2],[3,4]]").unwrap(), rect);
+	}
+}
@@ -2,7 +2,15 @@ mod number;
 mod compat;
 mod types;
 mod macros;
+pub mod algo;
 
 pub use types::vec2::Vec2;
+pub use types::vec2::Vec2ParseError;
+pub use types::vec3::Vec3;
 pub use types::rect::Rect;
-pub use number::Number;
\ No newline at end of file
+pub use types::rect::RectTransform;
+pub use types::rect::RectParseError;
+pub use number::Number;
+pub use number::PowInt;
+#[cfg(feature = "serde")]
+pub use compat::serde::{Lossless, Named};
\ No newline at end of file
@@ -1,8 +1,74 @@
-use num_traits::{FromPrimitive, Num, NumCast, ToPrimitive};
+use num_traits::{Float, FromPrimitive, Num, NumCast, ToPrimitive};
 
 pub trait Number: Num + FromPrimitive + ToPrimitive + Copy + PartialOrd + NumCast {
 }
 
 impl<N: Num + Copy + FromPrimitive + ToPrimitive + PartialOrd + NumCast> Number for N {
 
-}
\ No newline at end of file
+}
+
+/// Raising a number to an integer power, implemented for both the integer and the floating-point primitives.
+/// This is a separate trait so it does not clash with [Float::powi] when both are in scope.
+pub trait PowInt: Number {
+	/// Raises the number to the integer power `n`.
+	/// Floats use [Float::powi], integers use repeated multiplication.
+	/// A negative `n` gives the reciprocal, which truncates to zero for integers with a magnitude above one.
+	///
+	/// # Panics
+	/// Panics with a division by zero if an integer zero is raised to a negative power.
+	///
+	/// # Examples
+	/// ```
+	/// use mathie::PowInt;
+	/// assert_eq!(3i32.pow_int(4), 81);
+	/// assert_eq!(2.0f32.pow_int(3), 8.0);
+	/// assert_eq!(2.0f32.pow_int(-1), 0.5);
+	/// assert_eq!(2i32.pow_int(-1), 0);
+	/// ```
+	fn pow_int(self, n: i32) -> Self;
+}
+
+macro_rules! impl_pow_int_float {
+    ($($TYPE:ty)*) => {
+	    $(
+	    impl PowInt for $TYPE {
+		    #[inline(always)]
+		    fn pow_int(self, n: i32) -> Self {
+			    Float::powi(self, n)
+		    }
+	    }
+	    )*
+    };
+}
+
+macro_rules! impl_pow_int_integer {
+    ($($TYPE:ty)*) => {
+	    $(
+	    impl PowInt for $TYPE {
+		    fn pow_int(self, n: i32) -> Self {
+			    let mut base = self;
+			    let mut exp = n.unsigned_abs();
+			    let mut out = 1;
+			    while exp > 0 {
+				    if exp & 1 == 1 {
+					    out *= base;
+				    }
+				    exp >>= 1;
+				    if exp > 0 {
+					    base *= base;
+				    }
+			    }
+
+			    if n < 0 {
+				    1 / out
+			    } else {
+				    out
+			    }
+		    }
+	    }
+	    )*
+    };
+}
+
+impl_pow_int_float!(f32 f64);
+impl_pow_int_integer!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
@@ -1,2 +1,3 @@
 pub mod vec2;
+pub mod vec3;
 pub mod rect;
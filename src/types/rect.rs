@@ -1,7 +1,11 @@
 use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use num_traits::Float;
 use crate::impl_ops;
 use crate::Number;
-use crate::Vec2;
+use crate::{Vec2, Vec2ParseError};
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -10,6 +14,43 @@ pub struct Rect<T: Number> {
 	pub(crate) size: Vec2<T>,
 }
 
+/// A per-axis scale and offset which maps points from one rectangle to another, see [Rect::transform_to].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RectTransform<F: Number + Float> {
+	pub(crate) scale: Vec2<F>,
+	pub(crate) offset: Vec2<F>,
+}
+
+impl<F: Number + Float> RectTransform<F> {
+	/// Returns the per-axis scale.
+	#[inline(always)]
+	pub fn scale(self) -> Vec2<F> {
+		self.scale
+	}
+
+	/// Returns the offset which is added after scaling.
+	#[inline(always)]
+	pub fn offset(self) -> Vec2<F> {
+		self.offset
+	}
+
+	/// Maps the `point` with the transform.
+	#[inline(always)]
+	pub fn apply(self, point: Vec2<F>) -> Vec2<F> {
+		point * self.scale + self.offset
+	}
+
+	/// Gets the transform which maps points back, undoing [Self::apply].
+	#[inline(always)]
+	pub fn invert(self) -> RectTransform<F> {
+		let scale = Vec2::one() / self.scale;
+		RectTransform {
+			scale,
+			offset: -self.offset * scale,
+		}
+	}
+}
+
 impl<N: Number> Rect<N> {
 	pub fn new(origin: impl Into<[N; 2]>, size: impl Into<[N; 2]>) -> Rect<N> {
 		Rect {
@@ -28,6 +69,23 @@ impl<N: Number> Rect<N> {
 		}
 	}
 
+	/// Creates a rectangle of the given `size` which is centered on `center`.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::from_center([5.0, 5.0], [4.0, 2.0]);
+	/// assert_eq!(rect, Rect::new([3.0, 4.0], [4.0, 2.0]));
+	/// assert_eq!(rect.center(), Vec2::new(5.0, 5.0));
+	/// ```
+	pub fn from_center(center: impl Into<[N; 2]>, size: impl Into<[N; 2]>) -> Rect<N> {
+		let center = Vec2::from(center.into());
+		let size = Vec2::from(size.into());
+		Rect {
+			origin: center - size / N::from_u8(2).unwrap(),
+			size,
+		}
+	}
+
 	pub fn zero() -> Rect<N> {
 		Rect::new(Vec2::zero(), Vec2::zero())
 	}
@@ -55,6 +113,47 @@ impl<N: Number> Rect<N> {
 		})
 	}
 
+	/// Casts the values to `f32`, same as [Self::cast].
+	#[inline(always)]
+	pub fn as_f32(self) -> Rect<f32> {
+		self.cast()
+	}
+
+	/// Casts the values to `f64`, same as [Self::cast].
+	#[inline(always)]
+	pub fn as_f64(self) -> Rect<f64> {
+		self.cast()
+	}
+
+	/// Casts the values to `i32`, same as [Self::cast].
+	#[inline(always)]
+	pub fn as_i32(self) -> Rect<i32> {
+		self.cast()
+	}
+
+	/// Casts the values to `u32`, same as [Self::cast].
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// assert_eq!(Rect::new([0.5, 1.5], [2.0, 3.9]).as_u32(), Rect::new([0, 1], [2, 3]));
+	/// ```
+	#[inline(always)]
+	pub fn as_u32(self) -> Rect<u32> {
+		self.cast()
+	}
+
+	/// Casts the values to `i64`, same as [Self::cast].
+	#[inline(always)]
+	pub fn as_i64(self) -> Rect<i64> {
+		self.cast()
+	}
+
+	/// Casts the values to `usize`, same as [Self::cast].
+	#[inline(always)]
+	pub fn as_usize(self) -> Rect<usize> {
+		self.cast()
+	}
+
 
 	/// Checks if self intersects other. In other words it check if any of these rectangles touch each other.
 	/// This is very useful in cull testing.
@@ -110,6 +209,24 @@ impl<N: Number> Rect<N> {
 		self.contains_pos(rect.min()) && self.contains_pos(rect.max())
 	}
 
+	/// Checks if `other` is inside the interior of `self`, unlike [Self::contains_rect] sharing an edge does not count.
+	/// # Examples
+	///
+	/// ```
+	/// use mathie::Rect;
+	/// let rect = Rect::one();
+	/// assert!(rect.strictly_contains_rect(Rect::new([0.4, 0.4], [0.2, 0.2])));
+	/// assert!(!rect.strictly_contains_rect(Rect::new([0.0, 0.4], [0.2, 0.2])));
+	/// assert!(!rect.strictly_contains_rect(Rect::new([0.0, 0.0], [1.0, 1.0])));
+	/// ```
+	pub fn strictly_contains_rect(&self, other: Rect<N>) -> bool {
+		let min = self.min();
+		let max = self.max();
+		let o_min = other.min();
+		let o_max = other.max();
+		min.x() < o_min.x() && min.y() < o_min.y() && o_max.x() < max.x() && o_max.y() < max.y()
+	}
+
 	/// Checks if this position is inside this rectangle
 	/// # Examples
 	/// ```
@@ -129,6 +246,19 @@ impl<N: Number> Rect<N> {
 		min.x() <= pos.x() && pos.x() <= max.x() && min.y() <= pos.y() && pos.y() <= max.y()
 	}
 
+	/// Checks if this position is inside this rectangle after growing it by `margin` on every side.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::one();
+	/// assert!(rect.contains_pos_within(Vec2::new(1.1, 0.5), 0.2));
+	/// assert!(rect.contains_pos_within(Vec2::new(-0.1, -0.1), 0.2));
+	/// assert!(!rect.contains_pos_within(Vec2::new(1.3, 0.5), 0.2));
+	/// ```
+	pub fn contains_pos_within(&self, pos: Vec2<N>, margin: N) -> bool {
+		self.expand(Vec2::split(margin + margin)).contains_pos(pos)
+	}
+
 	/// Checks if the rectangle has a negative area
 	/// # Examples
 	/// ```
@@ -157,6 +287,25 @@ impl<N: Number> Rect<N> {
 		!(max.x() > min.x() && max.y() > min.y())
 	}
 
+	/// Flips the negative sides of the rectangle so that the origin is the minimum corner and the size is not negative.
+	/// The rectangle covers the same area as before, but is no longer [Self::is_negative].
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// assert_eq!(Rect::new([4.0, 1.0], [-3.0, 2.0]).normalize(), Rect::new([1.0, 1.0], [3.0, 2.0]));
+	/// assert_eq!(Rect::new([1.0, 1.0], [3.0, 2.0]).normalize(), Rect::new([1.0, 1.0], [3.0, 2.0]));
+	/// ```
+	pub fn normalize(self) -> Rect<N> {
+		let a = self.origin;
+		let b = self.origin + self.size;
+		let min = |a: N, b: N| if b < a { b } else { a };
+		let max = |a: N, b: N| if b > a { b } else { a };
+		Rect::new_min_max(
+			[min(a.x(), b.x()), min(a.y(), b.y())],
+			[max(a.x(), b.x()), max(a.y(), b.y())],
+		)
+	}
+
 	/// Makes the rectangle smaller in the x and y directions keeping its center.
 	/// # Examples
 	/// ```
@@ -191,6 +340,191 @@ impl<N: Number> Rect<N> {
 		out
 	}
 
+	/// Multiplies the size of the rectangle by `factor` while keeping its center in place.
+	/// Unlike `rect * factor`, which also scales the origin away from zero, this behaves like zooming in or out on the rectangle.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::one();
+	/// assert_eq!(rect.scale_from_center(Vec2::split(2.0)), Rect::new([-0.5, -0.5], [2.0, 2.0]));
+	/// assert_eq!(rect.scale_from_center(Vec2::split(2.0)).center(), rect.center());
+	/// ```
+	pub fn scale_from_center(self, factor: Vec2<N>) -> Rect<N> {
+		let size = self.size * factor;
+		Rect {
+			origin: self.center() - size / N::from_u8(2).unwrap(),
+			size,
+		}
+	}
+
+	/// Moves the rectangle by the `offset`, keeping its size.
+	/// Unlike `rect += offset`, which adds the offset to both the origin and the size, this only moves the origin.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let mut rect = Rect::new([1, 1], [2, 2]);
+	/// rect.translate(Vec2::new(3, -1));
+	/// assert_eq!(rect, Rect::new([4, 0], [2, 2]));
+	/// ```
+	#[inline(always)]
+	pub fn translate(&mut self, offset: Vec2<N>) {
+		self.origin += offset;
+	}
+
+	/// The same as [Self::translate] but returns the moved rectangle instead of modifying `self`.
+	/// Unlike `rect + offset`, the size of the rectangle is kept.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::new([1, 1], [2, 2]);
+	/// assert_eq!(rect.translated(Vec2::new(3, -1)), Rect::new([4, 0], [2, 2]));
+	/// assert_eq!(rect + Vec2::new(3, -1), Rect::new([4, 0], [5, 1]));
+	/// ```
+	#[inline(always)]
+	pub fn translated(mut self, offset: Vec2<N>) -> Rect<N> {
+		self.translate(offset);
+		self
+	}
+
+	/// Splits the rectangle into `n` rows of equal height, ordered from top to bottom.
+	/// For integers the height is truncated, so the last row is stretched to the bottom edge to cover the remainder.
+	/// Returns an empty vec if `n` is zero.
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// let rows = Rect::new([0.0, 0.0], [2.0, 1.0]).rows(2);
+	/// assert_eq!(rows, vec![Rect::new([0.0, 0.0], [2.0, 0.5]), Rect::new([0.0, 0.5], [2.0, 0.5])]);
+	/// ```
+	pub fn rows(self, n: usize) -> Vec<Rect<N>> {
+		if n == 0 {
+			return Vec::new();
+		}
+
+		let height = self.size.y() / N::from_usize(n).unwrap();
+		(0..n)
+			.map(|i| {
+				let top = height * N::from_usize(i).unwrap();
+				let height = if i == n - 1 { self.size.y() - top } else { height };
+				Rect {
+					origin: self.origin + Vec2::new(N::zero(), top),
+					size: Vec2::new(self.size.x(), height),
+				}
+			})
+			.collect()
+	}
+
+	/// Splits the rectangle into `n` columns of equal width, ordered from left to right.
+	/// For integers the width is truncated, so the last column is stretched to the right edge to cover the remainder.
+	/// Returns an empty vec if `n` is zero.
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// let columns = Rect::new([0.0, 0.0], [2.0, 1.0]).columns(2);
+	/// assert_eq!(columns, vec![Rect::new([0.0, 0.0], [1.0, 1.0]), Rect::new([1.0, 0.0], [1.0, 1.0])]);
+	/// ```
+	pub fn columns(self, n: usize) -> Vec<Rect<N>> {
+		if n == 0 {
+			return Vec::new();
+		}
+
+		let width = self.size.x() / N::from_usize(n).unwrap();
+		(0..n)
+			.map(|i| {
+				let left = width * N::from_usize(i).unwrap();
+				let width = if i == n - 1 { self.size.x() - left } else { width };
+				Rect {
+					origin: self.origin + Vec2::new(left, N::zero()),
+					size: Vec2::new(width, self.size.y()),
+				}
+			})
+			.collect()
+	}
+
+	/// Splits the rectangle into a grid of `cols` by `rows` equally sized cells, yielded in row-major order.
+	/// Every cell has a size of `size / (cols, rows)`. For integers that size is truncated,
+	/// so the last column and row are stretched to the edges to cover the remainder, like [Self::rows] and [Self::columns].
+	/// Yields nothing if `cols` or `rows` is zero.
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// let cells: Vec<_> = Rect::new([0, 0], [4, 2]).subdivide(2, 2).collect();
+	/// assert_eq!(cells, vec![
+	///     Rect::new([0, 0], [2, 1]),
+	///     Rect::new([2, 0], [2, 1]),
+	///     Rect::new([0, 1], [2, 1]),
+	///     Rect::new([2, 1], [2, 1]),
+	/// ]);
+	/// ```
+	pub fn subdivide(self, cols: usize, rows: usize) -> impl Iterator<Item = Rect<N>> {
+		let cell = if cols == 0 || rows == 0 {
+			Vec2::zero()
+		} else {
+			self.size / Vec2::new(N::from_usize(cols).unwrap(), N::from_usize(rows).unwrap())
+		};
+
+		(0..rows).flat_map(move |row| {
+			(0..cols).map(move |col| {
+				let origin = self.origin + cell * Vec2::new(N::from_usize(col).unwrap(), N::from_usize(row).unwrap());
+				let max = self.max();
+				let size = Vec2::new(
+					if col == cols - 1 { max.x() - origin.x() } else { cell.x() },
+					if row == rows - 1 { max.y() - origin.y() } else { cell.y() },
+				);
+				Rect { origin, size }
+			})
+		})
+	}
+
+	/// Splits the rectangle into a left and a right part at the absolute x coordinate `at`.
+	/// The coordinate is clamped to the rectangle, so if `at` is left of the rectangle the left part is empty
+	/// and if it is right of the rectangle the right part is empty.
+	///
+	/// # Arguments
+	///
+	/// * `at`: The x coordinate to split at.
+	///
+	/// returns: (Rect<N>, Rect<N>) The left and the right part.
+	///
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// let rect = Rect::new([0, 0], [10, 4]);
+	/// assert_eq!(rect.split_horizontal(3), (Rect::new([0, 0], [3, 4]), Rect::new([3, 0], [7, 4])));
+	/// assert_eq!(rect.split_horizontal(20), (rect, Rect::new([10, 0], [0, 4])));
+	/// ```
+	pub fn split_horizontal(self, at: N) -> (Rect<N>, Rect<N>) {
+		let at = clamp(at, self.left(), self.right());
+		(
+			Rect::new_min_max(self.min(), [at, self.bottom()]),
+			Rect::new_min_max([at, self.top()], self.max()),
+		)
+	}
+
+	/// Splits the rectangle into a top and a bottom part at the absolute y coordinate `at`.
+	/// The coordinate is clamped to the rectangle, so if `at` is above the rectangle the top part is empty
+	/// and if it is below the rectangle the bottom part is empty.
+	///
+	/// # Arguments
+	///
+	/// * `at`: The y coordinate to split at.
+	///
+	/// returns: (Rect<N>, Rect<N>) The top and the bottom part.
+	///
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// let rect = Rect::new([0, 0], [4, 10]);
+	/// assert_eq!(rect.split_vertical(3), (Rect::new([0, 0], [4, 3]), Rect::new([0, 3], [4, 7])));
+	/// assert_eq!(rect.split_vertical(-5), (Rect::new([0, 0], [4, 0]), rect));
+	/// ```
+	pub fn split_vertical(self, at: N) -> (Rect<N>, Rect<N>) {
+		let at = clamp(at, self.top(), self.bottom());
+		(
+			Rect::new_min_max(self.min(), [self.right(), at]),
+			Rect::new_min_max([self.left(), at], self.max()),
+		)
+	}
+
 	/// Gets the top left corner
 	#[inline(always)]
 	pub fn top_left(self) -> Vec2<N> {
@@ -215,6 +549,19 @@ impl<N: Number> Rect<N> {
 		self.origin() + Vec2::new(N::zero(), self.size.y())
 	}
 
+	/// Gets all four corners in a fixed order: `[top_left, top_right, bottom_right, bottom_left]`.
+	/// With the Y axis pointing down this is clockwise, starting at the origin.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::new([1, 1], [2, 3]);
+	/// assert_eq!(rect.corners(), [Vec2::new(1, 1), Vec2::new(3, 1), Vec2::new(3, 4), Vec2::new(1, 4)]);
+	/// ```
+	#[inline(always)]
+	pub fn corners(self) -> [Vec2<N>; 4] {
+		[self.top_left(), self.top_right(), self.bottom_right(), self.bottom_left()]
+	}
+
 	/// Gets the top Y coordinate
 	#[inline(always)]
 	pub fn top(self) -> N {
@@ -290,23 +637,447 @@ impl<N: Number> Rect<N> {
 	pub fn size(self) -> Vec2<N> {
 		self.size
 	}
+
+	/// Gets the area of the rectangle, `width * height`.
+	/// A negative rectangle (see [Self::is_negative]) with one negative side has a negative area.
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// assert_eq!(Rect::<f32>::one().area(), 1.0);
+	/// assert_eq!(Rect::new([0.0, 0.0], [2.0, 3.0]).area(), 6.0);
+	/// assert_eq!(Rect::new([0.0, 0.0], [-2.0, 3.0]).area(), -6.0);
+	/// ```
+	#[inline(always)]
+	pub fn area(self) -> N {
+		self.size.mul_xy()
+	}
+
+	/// Gets the perimeter of the rectangle, `2 * (width + height)`.
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// assert_eq!(Rect::<f32>::one().perimeter(), 4.0);
+	/// assert_eq!(Rect::new([0, 0], [2, 3]).perimeter(), 10);
+	/// ```
+	#[inline(always)]
+	pub fn perimeter(self) -> N {
+		let half = self.size.add_xy();
+		half + half
+	}
 }
 
 impl<N: Number + Ord> Rect<N> {
 	/// Returns the intersection between two rectangles.
+	/// If the rectangles do not intersect (see [Self::intersects_rect]) this returns [Self::zero].
+	/// Rectangles which only touch intersect in a rectangle with a size of zero on that axis.
+	/// # Examples
+	///
+	/// ```
+	/// use mathie::Rect;
+	/// let rect = Rect::new([0, 0], [10, 10]);
+	/// assert_eq!(rect.intersection(Rect::new([5, 2], [10, 4])), Rect::new([5, 2], [5, 4]));
+	/// assert_eq!(rect.intersection(Rect::new([10, 0], [10, 10])), Rect::new([10, 0], [0, 10]));
+	/// assert_eq!(rect.intersection(Rect::new([11, 11], [10, 10])), Rect::zero());
+	/// ```
 	pub fn intersection(&self, other: Self) -> Self {
-		let min = Rect::min(*self);
-		let max = Rect::max(*self);
-		let o_min = other.min();
-		let o_max = other.max();
+		if !self.intersects_rect(other) {
+			return Rect::zero();
+		}
+
+		let min = Rect::min(*self).max(Rect::min(other));
+		let max = Rect::max(*self).min(Rect::max(other));
+		Rect::new_min_max(min, max)
+	}
+
+	/// Gets the smallest rectangle which contains both `self` and `other`.
+	/// Empty rectangles are not skipped, their position still extends the result,
+	/// so filter them out with [Self::is_empty] first if that is not wanted.
+	/// A negative rectangle contributes its origin as the minimum and `origin + size` as the maximum as they are,
+	/// which means the union of only negative rectangles is negative as well.
+	/// # Examples
+	///
+	/// ```
+	/// use mathie::Rect;
+	/// let rect = Rect::new([0, 0], [2, 2]);
+	/// assert_eq!(rect.union(Rect::new([1, 3], [4, 1])), Rect::new([0, 0], [5, 4]));
+	/// assert_eq!(rect.union(Rect::new([1, 1], [1, 1])), rect);
+	/// ```
+	pub fn union(&self, other: Self) -> Self {
+		let min = Rect::min(*self).min(Rect::min(other));
+		let max = Rect::max(*self).max(Rect::max(other));
+		Rect::new_min_max(min, max)
+	}
+
+	/// Gets the point inside of the rectangle, or on its edge, which is the closest to `p`.
+	/// Points which are already inside of the rectangle are returned unchanged.
+	/// # Examples
+	///
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::new([0, 0], [10, 10]);
+	/// assert_eq!(rect.clamp_point(Vec2::new(5, 5)), Vec2::new(5, 5));
+	/// assert_eq!(rect.clamp_point(Vec2::new(-5, 15)), Vec2::new(0, 10));
+	/// ```
+	pub fn clamp_point(self, p: Vec2<N>) -> Vec2<N> {
+		p.clamp(self.min(), self.max())
+	}
+
+	/// Checks if the rectangles share a full or partial edge without their interiors overlapping.
+	/// Unlike [Self::intersects_rect], rectangles which only touch on a corner or which overlap are not adjacent.
+	/// # Examples
+	///
+	/// ```
+	/// use mathie::Rect;
+	/// let rect = Rect::new([0, 0], [2, 2]);
+	/// assert!(rect.is_adjacent(Rect::new([2, 1], [2, 2])));
+	/// assert!(!rect.is_adjacent(Rect::new([1, 1], [2, 2])));
+	/// assert!(!rect.is_adjacent(Rect::new([3, 0], [2, 2])));
+	/// assert!(!rect.is_adjacent(Rect::new([2, 2], [2, 2])));
+	/// ```
+	pub fn is_adjacent(&self, other: Self) -> bool {
+		let overlaps_x = self.left().max(other.left()) < self.right().min(other.right());
+		let overlaps_y = self.top().max(other.top()) < self.bottom().min(other.bottom());
+		let touches_x = self.right() == other.left() || other.right() == self.left();
+		let touches_y = self.bottom() == other.top() || other.bottom() == self.top();
+		(touches_x && overlaps_y) || (touches_y && overlaps_x)
+	}
+}
+
+impl<F: Number + Float> Rect<F> {
+	/// Gets the minimum translation vector which, when added to the origin of `self`, separates it from `other`.
+	/// Returns None if the rectangles do not overlap. Rectangles which only touch are not overlapping.
+	///
+	/// # Arguments
+	///
+	/// * `other`: The rectangle to move away from.
+	///
+	/// returns: Option<Vec2<F>>
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::one();
+	/// assert_eq!(rect.mtv(Rect::new([0.75, 0.0], [1.0, 1.0])), Some(Vec2::new(-0.25, 0.0)));
+	/// assert_eq!(rect.mtv(Rect::new([1.0, 0.0], [1.0, 1.0])), None);
+	/// ```
+	pub fn mtv(self, other: Rect<F>) -> Option<Vec2<F>> {
+		let min = self.min().maxf(other.min());
+		let max = self.max().minf(other.max());
+		let depth = max - min;
+		if depth.x() <= F::zero() || depth.y() <= F::zero() {
+			return None;
+		}
 
+		let direction = (self.center() - other.center()).map(|v| if v < F::zero() { -F::one() } else { F::one() });
+		if depth.x() <= depth.y() {
+			Some(Vec2::new(depth.x() * direction.x(), F::zero()))
+		} else {
+			Some(Vec2::new(F::zero(), depth.y() * direction.y()))
+		}
+	}
+
+	/// Linearly interpolates the origin and the size of the rectangles separately, see [Vec2::lerp].
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// let from = Rect::new([0.0, 0.0], [10.0, 10.0]);
+	/// let to = Rect::new([10.0, 20.0], [20.0, 0.0]);
+	/// assert_eq!(from.lerp(to, 0.0), from);
+	/// assert_eq!(from.lerp(to, 1.0), to);
+	/// assert_eq!(from.lerp(to, 0.5), Rect::new([5.0, 10.0], [15.0, 5.0]));
+	/// ```
+	#[inline(always)]
+	pub fn lerp(self, other: Rect<F>, t: F) -> Rect<F> {
+		Rect {
+			origin: self.origin.lerp(other.origin, t),
+			size: self.size.lerp(other.size, t),
+		}
+	}
+
+	/// Rounds the origin and the size to the nearest integer independently, see [Vec2::round].
+	#[inline(always)]
+	pub fn round(self) -> Rect<F> {
+		Rect {
+			origin: self.origin.round(),
+			size: self.size.round(),
+		}
+	}
+
+	/// Rounds the origin and the size down independently, see [Vec2::floor].
+	#[inline(always)]
+	pub fn floor(self) -> Rect<F> {
 		Rect {
-			origin: Vec2::new(min.x().max(o_min.x()), min.y().max(o_min.y())),
-			size: Vec2::new(max.x().min(o_max.x()), max.y().min(o_max.y()))
+			origin: self.origin.floor(),
+			size: self.size.floor(),
+		}
+	}
+
+	/// Rounds the origin and the size up independently, see [Vec2::ceil].
+	#[inline(always)]
+	pub fn ceil(self) -> Rect<F> {
+		Rect {
+			origin: self.origin.ceil(),
+			size: self.size.ceil(),
+		}
+	}
+
+	/// Gets the smallest rectangle with integer corners which fully contains this rectangle,
+	/// by rounding the min corner down and the max corner up. Useful to turn a float rectangle into pixel bounds.
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// let rect = Rect::new([0.3, 0.3], [1.4, 1.4]);
+	/// assert_eq!(rect.round_out(), Rect::new([0.0, 0.0], [2.0, 2.0]));
+	/// assert!(rect.round_out().contains_rect(rect));
+	/// ```
+	#[inline(always)]
+	pub fn round_out(self) -> Rect<F> {
+		Rect::new_min_max(self.min().floor(), self.max().ceil())
+	}
+
+	/// Gets the point inside the rectangle at the normalized `uv` coordinates,
+	/// where `(0, 0)` is the top left corner and `(1, 1)` is the bottom right corner.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::new([1.0, 1.0], [2.0, 4.0]);
+	/// assert_eq!(rect.point_at_uv(Vec2::new(0.5, 0.5)), rect.center());
+	/// assert_eq!(rect.point_at_uv(Vec2::new(1.0, 1.0)), rect.max());
+	/// ```
+	#[inline(always)]
+	pub fn point_at_uv(self, uv: Vec2<F>) -> Vec2<F> {
+		self.origin() + self.size() * uv
+	}
+
+	/// Gets the normalized position of the `point` within the rectangle, the inverse of [Self::point_at_uv].
+	/// Values outside of `[0, 1]` mean the point is outside of the rectangle.
+	/// An axis with a size of zero divides by zero, giving infinity or NaN on that axis.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::new([1.0, 1.0], [2.0, 4.0]);
+	/// assert_eq!(rect.uv_of(rect.center()), Vec2::new(0.5, 0.5));
+	/// assert_eq!(rect.point_at_uv(rect.uv_of(Vec2::new(1.5, 4.0))), Vec2::new(1.5, 4.0));
+	/// ```
+	#[inline(always)]
+	pub fn uv_of(self, point: Vec2<F>) -> Vec2<F> {
+		(point - self.origin()) / self.size()
+	}
+
+	/// The same as [Self::clamp_point] but for floating-point numbers.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::new([0.0, 0.0], [10.0, 10.0]);
+	/// assert_eq!(rect.clamp_pointf(Vec2::new(5.0, 5.0)), Vec2::new(5.0, 5.0));
+	/// assert_eq!(rect.clamp_pointf(Vec2::new(-5.0, 15.0)), Vec2::new(0.0, 10.0));
+	/// ```
+	#[inline(always)]
+	pub fn clamp_pointf(self, p: Vec2<F>) -> Vec2<F> {
+		p.clampf(self.min(), self.max())
+	}
+
+	/// Gets the point inside of the rectangle, or on its edge, which is the closest to `p`.
+	/// This is the same as [Self::clamp_pointf].
+	#[inline(always)]
+	pub fn closest_point(self, p: Vec2<F>) -> Vec2<F> {
+		self.clamp_pointf(p)
+	}
+
+	/// Gets the euclidean distance from `p` to the closest point of the rectangle, zero if the point is inside.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::new([0.0, 0.0], [10.0, 10.0]);
+	/// assert_eq!(rect.distance_to_point(Vec2::new(5.0, 5.0)), 0.0);
+	/// assert_eq!(rect.distance_to_point(Vec2::new(13.0, 14.0)), 5.0);
+	/// assert_eq!(rect.distance_to_point(Vec2::new(5.0, -2.0)), 2.0);
+	/// ```
+	#[inline(always)]
+	pub fn distance_to_point(self, p: Vec2<F>) -> F {
+		self.closest_point(p).distance(p)
+	}
+
+	/// Gets the aspect ratio of the rectangle, `width / height`.
+	/// A rectangle with a height of zero returns infinity.
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// assert_eq!(Rect::new([0.0, 0.0], [1920.0, 1080.0]).aspect_ratio(), 16.0 / 9.0);
+	/// assert_eq!(Rect::new([0.0, 0.0], [1.0, 0.0]).aspect_ratio(), f64::INFINITY);
+	/// ```
+	#[inline(always)]
+	pub fn aspect_ratio(self) -> F {
+		if self.size().y() == F::zero() {
+			return F::infinity();
+		}
+
+		self.size().div_xy()
+	}
+
+	/// Checks if the rectangle is wider than it is tall. A square is neither landscape nor portrait.
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// assert!(Rect::new([0.0, 0.0], [2.0, 1.0]).is_landscape());
+	/// assert!(!Rect::new([0.0, 0.0], [1.0, 1.0]).is_landscape());
+	/// ```
+	#[inline(always)]
+	pub fn is_landscape(self) -> bool {
+		self.aspect_ratio() > F::one()
+	}
+
+	/// Checks if the rectangle is taller than it is wide. A square is neither landscape nor portrait.
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// assert!(Rect::new([0.0, 0.0], [1.0, 2.0]).is_portrait());
+	/// assert!(!Rect::new([0.0, 0.0], [1.0, 1.0]).is_portrait());
+	/// ```
+	#[inline(always)]
+	pub fn is_portrait(self) -> bool {
+		self.aspect_ratio() < F::one()
+	}
+
+	/// The same as [Self::union] but for floating-point numbers.
+	/// # Examples
+	/// ```
+	/// use mathie::Rect;
+	/// let rect = Rect::new([0.0, 0.0], [2.0, 2.0]);
+	/// assert_eq!(rect.unionf(Rect::new([1.0, 3.0], [4.0, 1.0])), Rect::new([0.0, 0.0], [5.0, 4.0]));
+	/// assert_eq!(rect.unionf(Rect::new([3.0, 3.0], [0.0, 0.0])), Rect::new([0.0, 0.0], [3.0, 3.0]));
+	/// ```
+	pub fn unionf(self, other: Rect<F>) -> Rect<F> {
+		Rect::new_min_max(self.min().minf(other.min()), self.max().maxf(other.max()))
+	}
+
+	/// Gets the smallest rectangle which contains both `self` and the bounding box of the circle.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let rect = Rect::one().grow_to_contain_circle(Vec2::new(3.0, 3.0), 1.0);
+	/// assert_eq!(rect, Rect::new([0.0, 0.0], [4.0, 4.0]));
+	/// assert!(rect.contains_pos(Vec2::new(4.0, 4.0)));
+	/// ```
+	pub fn grow_to_contain_circle(self, center: Vec2<F>, radius: F) -> Rect<F> {
+		self.unionf(Rect::new_min_max(center - radius, center + radius))
+	}
+
+	/// Gets the transform which maps points inside of `self` to the same relative position inside of `target`.
+	/// This is cheaper than recomputing the mapping for every point.
+	/// An axis where `self` has a size of zero divides by zero, giving infinity or NaN on that axis.
+	/// # Examples
+	/// ```
+	/// use mathie::{Rect, Vec2};
+	/// let data = Rect::new([0.0, 0.0], [100.0, 10.0]);
+	/// let screen = Rect::new([10.0, 10.0], [200.0, 200.0]);
+	/// let transform = data.transform_to(screen);
+	/// assert_eq!(transform.apply(data.center()), screen.center());
+	/// assert_eq!(transform.apply(data.max()), screen.max());
+	/// ```
+	pub fn transform_to(self, target: Rect<F>) -> RectTransform<F> {
+		let scale = target.size() / self.size();
+		RectTransform {
+			scale,
+			offset: target.origin() - self.origin() * scale,
+		}
+	}
+
+	/// Gets which of the `buckets` equal-width columns along the x axis the `value` falls into.
+	/// Returns None if the value is outside of the horizontal range of the rectangle or `buckets` is zero.
+	/// The right edge belongs to the last bucket.
+	pub fn bucket_x(self, value: F, buckets: usize) -> Option<usize> {
+		if buckets == 0 || value < self.left() || value > self.right() {
+			return None;
+		}
+
+		let t = (value - self.left()) / self.size().x();
+		let bucket = (t * F::from_usize(buckets)?).floor().to_usize()?;
+		Some(bucket.min(buckets - 1))
+	}
+}
+
+
+impl<N: Number> Default for Rect<N> {
+	#[inline(always)]
+	fn default() -> Self {
+		Rect::zero()
+	}
+}
+
+/// Formats the rectangle as `x,y,w,h`, the origin followed by the size.
+/// The formatting options like the precision are applied to each of the values.
+/// # Examples
+/// ```
+/// use mathie::Rect;
+/// assert_eq!(Rect::new([1, 2], [3, 4]).to_string(), "1,2,3,4");
+/// assert_eq!(format!("{:.1}", Rect::new([0.0, 0.5], [1.0, 1.0])), "0.0,0.5,1.0,1.0");
+/// ```
+impl<N: Number + Display> Display for Rect<N> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		for (i, value) in self.origin.into_iter().chain(self.size).enumerate() {
+			if i != 0 {
+				f.write_str(",")?;
+			}
+			Display::fmt(&value, f)?;
+		}
+		Ok(())
+	}
+}
+
+/// The error returned when parsing a [Rect] from a string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RectParseError {
+	/// The string did not contain exactly four values, holds the amount of values found.
+	WrongComponentCount(usize),
+	/// One of the values could not be parsed as a number, holds the value which failed.
+	InvalidNumber(String),
+}
+
+impl From<Vec2ParseError> for RectParseError {
+	fn from(value: Vec2ParseError) -> Self {
+		match value {
+			Vec2ParseError::WrongComponentCount(count) => RectParseError::WrongComponentCount(count),
+			Vec2ParseError::InvalidNumber(value) => RectParseError::InvalidNumber(value),
+		}
+	}
+}
+
+impl Display for RectParseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			RectParseError::WrongComponentCount(count) => write!(f, "expected 4 values but found {count}"),
+			RectParseError::InvalidNumber(value) => write!(f, "invalid number \"{value}\""),
 		}
 	}
 }
 
+impl std::error::Error for RectParseError {}
+
+/// Parses the rectangle from four comma separated values `x,y,w,h`, the same format as [Display].
+/// # Examples
+/// ```
+/// use mathie::{Rect, RectParseError};
+/// assert_eq!("10, 20, 640, 480".parse(), Ok(Rect::new([10, 20], [640, 480])));
+/// assert_eq!("10,20,640".parse::<Rect<u32>>(), Err(RectParseError::WrongComponentCount(3)));
+/// ```
+impl<N: Number + FromStr> FromStr for Rect<N> {
+	type Err = RectParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let count = s.split(',').count();
+		if count != 4 {
+			return Err(RectParseError::WrongComponentCount(count));
+		}
+
+		// Split after the second value, each half is a Vec2.
+		let (split, _) = s.match_indices(',').nth(1).unwrap();
+		let origin = s[..split].parse::<Vec2<N>>()?;
+		let size = s[split + 1..].parse::<Vec2<N>>()?;
+		Ok(Rect { origin, size })
+	}
+}
 
 impl<N: Number> PartialEq<Self> for Rect<N> {
 	#[inline(always)]
@@ -317,6 +1088,13 @@ impl<N: Number> PartialEq<Self> for Rect<N> {
 
 impl<N: Number> Eq for Rect<N> {}
 
+impl<N: Number + Hash> Hash for Rect<N> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.origin.hash(state);
+		self.size.hash(state);
+	}
+}
+
 impl<N: Number> PartialOrd<Self> for Rect<N> {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
 		let origin = self.origin().partial_cmp(&other.origin())?;
@@ -388,6 +1166,18 @@ macro_rules! impl_op {
 }
 
 impl_ops!(impl_op);
+
+/// Clamps the value for types which are only [PartialOrd], like floats.
+fn clamp<N: Number>(value: N, min: N, max: N) -> N {
+	if value < min {
+		min
+	} else if value > max {
+		max
+	} else {
+		value
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -411,4 +1201,242 @@ mod tests {
 		assert!(rect.intersects_rect(Rect::new([-0.1f32, -0.1f32], [0.1, 0.1])));
 		assert!(!rect.intersects_rect(Rect::new([1.11f32, 1.11], [1.0, 1.0])));
 	}
+
+	#[test]
+	fn mtv_horizontal() {
+		let rect = Rect::new([0.0f32, 0.0], [1.0, 1.0]);
+		let other = Rect::new([0.9f32, 0.2], [1.0, 1.0]);
+		let mtv = rect.mtv(other).unwrap();
+		assert!(mtv.y() == 0.0 && mtv.x() < 0.0);
+
+		let moved = Rect::new(rect.origin() + mtv, rect.size());
+		assert_eq!(moved.mtv(other), None);
+	}
+
+	#[test]
+	fn mtv_vertical() {
+		let rect = Rect::new([0.0f32, 0.0], [1.0, 1.0]);
+		let other = Rect::new([0.2f32, -0.75], [1.0, 1.0]);
+		let mtv = rect.mtv(other).unwrap();
+		assert_eq!(mtv, Vec2::new(0.0, 0.25));
+
+		let moved = Rect::new(rect.origin() + mtv, rect.size());
+		assert_eq!(moved.mtv(other), None);
+		assert_eq!(rect.mtv(Rect::new([2.0, 2.0], [1.0, 1.0])), None);
+	}
+
+	#[test]
+	fn bucket_x() {
+		let rect = Rect::new([1.0f32, 0.0], [4.0, 1.0]);
+		assert_eq!(rect.bucket_x(1.0, 4), Some(0));
+		assert_eq!(rect.bucket_x(2.5, 4), Some(1));
+		assert_eq!(rect.bucket_x(3.0, 4), Some(2));
+		assert_eq!(rect.bucket_x(5.0, 4), Some(3));
+		assert_eq!(rect.bucket_x(5.1, 4), None);
+		assert_eq!(rect.bucket_x(0.9, 4), None);
+		assert_eq!(rect.bucket_x(2.0, 0), None);
+	}
+
+	#[test]
+	fn adjacent() {
+		let rect = Rect::new([0, 0], [2, 2]);
+		assert!(rect.is_adjacent(Rect::new([2, 0], [2, 2])));
+		assert!(rect.is_adjacent(Rect::new([1, 2], [3, 1])));
+		assert!(rect.is_adjacent(Rect::new([-1, -1], [1, 2])));
+		assert!(!rect.is_adjacent(Rect::new([1, 1], [2, 2])));
+		assert!(!rect.is_adjacent(Rect::new([2, 2], [2, 2])));
+		assert!(!rect.is_adjacent(Rect::new([3, 0], [2, 2])));
+	}
+
+	#[test]
+	fn rows() {
+		let rect = Rect::new([0.0f32, 0.0], [1.0, 1.0]);
+		let rows = rect.rows(4);
+		assert_eq!(rows.len(), 4);
+		for (i, row) in rows.iter().enumerate() {
+			assert_eq!(row.size(), Vec2::new(1.0, 0.25));
+			assert_eq!(row.top(), i as f32 * 0.25);
+			assert!(rect.contains_rect(*row));
+		}
+		assert_eq!(rows[0].top(), rect.top());
+		assert_eq!(rows[3].bottom(), rect.bottom());
+		assert!(rect.rows(0).is_empty());
+		assert!(rect.columns(0).is_empty());
+	}
+
+	#[test]
+	fn rows_integer() {
+		let rect = Rect::new([0, 0], [10, 10]);
+		let rows = rect.rows(3);
+		assert_eq!(rows, vec![Rect::new([0, 0], [10, 3]), Rect::new([0, 3], [10, 3]), Rect::new([0, 6], [10, 4])]);
+		assert_eq!(rows[2].bottom(), rect.bottom());
+		assert_eq!(rows.iter().map(|row| row.area()).sum::<i32>(), rect.area());
+
+		let columns = rect.columns(3);
+		assert_eq!(columns, vec![Rect::new([0, 0], [3, 10]), Rect::new([3, 0], [3, 10]), Rect::new([6, 0], [4, 10])]);
+		assert_eq!(columns[2].right(), rect.right());
+	}
+
+	#[test]
+	fn transform_to() {
+		let from = Rect::new([-4.0f32, 2.0], [8.0, 4.0]);
+		let to = Rect::new([0.0f32, 0.0], [2.0, 16.0]);
+		let transform = from.transform_to(to);
+		assert_eq!(transform.apply(from.min()), to.min());
+		assert_eq!(transform.apply(from.max()), to.max());
+		assert_eq!(transform.apply(from.top_right()), to.top_right());
+
+		assert_eq!(transform.scale(), Vec2::new(0.25, 4.0));
+		assert_eq!(transform.offset(), Vec2::new(1.0, -8.0));
+
+		let inverse = transform.invert();
+		for point in [Vec2::new(0.0f32, 0.0), Vec2::new(-4.0, 6.0), Vec2::new(1.5, -2.25), Vec2::new(100.0, 3.0)] {
+			assert_eq!(inverse.apply(transform.apply(point)), point);
+		}
+	}
+
+	#[test]
+	fn transform_to_degenerate() {
+		let from = Rect::new([2.0f32, 0.0], [0.0, 4.0]);
+		let to = Rect::new([0.0f32, 0.0], [8.0, 8.0]);
+		let point = from.transform_to(to).apply(Vec2::new(2.0, 2.0));
+		assert!(!point.x().is_finite());
+		assert_eq!(point.y(), 4.0);
+	}
+
+	#[test]
+	fn hash_map_key() {
+		let mut set = std::collections::HashSet::new();
+		assert!(set.insert(Rect::new([0, 0], [1, 1])));
+		assert!(!set.insert(Rect::new([0, 0], [1, 1])));
+		assert!(set.insert(Rect::new([0, 1], [1, 0])));
+	}
+
+	#[test]
+	fn default() {
+		#[derive(Default)]
+		struct State {
+			position: Vec2<f32>,
+			bounds: Rect<i32>,
+		}
+
+		let state = State::default();
+		assert_eq!(state.position, Vec2::zero());
+		assert_eq!(state.bounds, Rect::zero());
+	}
+
+	#[test]
+	fn intersection() {
+		// The same cases as the intersects_rect examples, scaled up to integers.
+		let rect = Rect::new([0, 0], [100, 100]);
+		let cases = [
+			(Rect::new([0, 0], [100, 100]), Some(Rect::new([0, 0], [100, 100]))),
+			(Rect::new([40, 40], [20, 20]), Some(Rect::new([40, 40], [20, 20]))),
+			(Rect::new([100, 100], [100, 100]), Some(Rect::new([100, 100], [0, 0]))),
+			(Rect::new([0, 50], [50, 100]), Some(Rect::new([0, 50], [50, 50]))),
+			(Rect::new([110, 110], [100, 100]), None),
+			(Rect::new([-10, -10], [9, 9]), None),
+		];
+
+		for (other, expected) in cases {
+			let intersection = rect.intersection(other);
+			assert_eq!(intersection, other.intersection(rect));
+			assert_eq!(rect.intersects_rect(other), expected.is_some());
+			match expected {
+				Some(expected) => {
+					assert_eq!(intersection, expected);
+					assert!(rect.contains_rect(intersection));
+					assert!(other.contains_rect(intersection));
+				}
+				None => assert_eq!(intersection, Rect::zero()),
+			}
+		}
+	}
+
+	#[test]
+	fn normalize() {
+		// A selection dragged from the bottom right to the top left.
+		let rect = Rect::new([10, 8], [-6, -4]);
+		assert!(rect.is_negative());
+		assert!(!rect.contains_pos(Vec2::new(6, 6)));
+
+		let normalized = rect.normalize();
+		assert!(!normalized.is_negative());
+		assert_eq!(normalized, Rect::new([4, 4], [6, 4]));
+		assert_eq!(normalized.min(), Vec2::new(4, 4));
+		assert_eq!(normalized.max(), Vec2::new(10, 8));
+		assert!(normalized.contains_pos(Vec2::new(6, 6)));
+		assert!(normalized.intersects_rect(Rect::new([5, 5], [1, 1])));
+		assert_eq!(normalized.normalize(), normalized);
+	}
+
+	#[test]
+	fn subdivide() {
+		let rect = Rect::new([2, 4], [12, 6]);
+		let cells: Vec<Rect<i32>> = rect.subdivide(3, 2).collect();
+		assert_eq!(cells.len(), 6);
+		assert_eq!(cells.iter().map(|cell| cell.area()).sum::<i32>(), rect.area());
+		assert_eq!(cells.iter().fold(cells[0], |bounds, cell| bounds.union(*cell)), rect);
+		for (i, cell) in cells.iter().enumerate() {
+			assert_eq!(cell.size(), Vec2::new(4, 3));
+			assert_eq!(cell.origin(), rect.origin() + Vec2::new(i as i32 % 3 * 4, i as i32 / 3 * 3));
+			assert!(rect.contains_rect(*cell));
+			for other in &cells[i + 1..] {
+				assert!(cell.intersection(*other).is_empty());
+			}
+		}
+
+		assert_eq!(rect.subdivide(0, 2).count(), 0);
+		assert_eq!(rect.subdivide(2, 0).count(), 0);
+	}
+
+	#[test]
+	fn subdivide_integer_remainder() {
+		let rect = Rect::new([0, 0], [10, 10]);
+		let cells: Vec<Rect<i32>> = rect.subdivide(3, 3).collect();
+		assert_eq!(cells.len(), 9);
+		assert_eq!(cells[8], Rect::new([6, 6], [4, 4]));
+		assert_eq!(cells.iter().map(|cell| cell.area()).sum::<i32>(), rect.area());
+		assert_eq!(cells.iter().fold(cells[0], |bounds, cell| bounds.union(*cell)), rect);
+		for (i, cell) in cells.iter().enumerate() {
+			for other in &cells[i + 1..] {
+				assert!(cell.intersection(*other).is_empty());
+			}
+		}
+
+		// A single column matches rows and a single row matches columns.
+		assert_eq!(rect.subdivide(1, 3).collect::<Vec<_>>(), rect.rows(3));
+		assert_eq!(rect.subdivide(3, 1).collect::<Vec<_>>(), rect.columns(3));
+	}
+
+	#[test]
+	fn rounding() {
+		let rect = Rect::new([0.3, 0.3], [1.4, 1.4]);
+		assert_eq!(rect.round(), Rect::new([0.0, 0.0], [1.0, 1.0]));
+		assert_eq!(rect.floor(), Rect::new([0.0, 0.0], [1.0, 1.0]));
+		assert_eq!(rect.ceil(), Rect::new([1.0, 1.0], [2.0, 2.0]));
+		assert_eq!(rect.round_out(), Rect::new([0.0, 0.0], [2.0, 2.0]));
+
+		// Rounding independently can lose part of the rectangle, round_out never does.
+		assert!(!rect.round().contains_rect(rect));
+		assert!(!rect.ceil().contains_rect(rect));
+		assert!(rect.round_out().contains_rect(rect));
+	}
+
+	#[test]
+	fn parse() {
+		let rects = [
+			Rect::new([0.1, -2.5], [1e-7, 3.0]),
+			Rect::new([f64::MIN_POSITIVE, 1.0 / 3.0], [f64::MAX, 0.0]),
+		];
+		for rect in rects {
+			assert_eq!(rect.to_string().parse::<Rect<f64>>(), Ok(rect));
+		}
+		assert_eq!(Rect::new([-1, 2], [3, 4]).to_string().parse::<Rect<i32>>(), Ok(Rect::new([-1, 2], [3, 4])));
+
+		assert_eq!("".parse::<Rect<f32>>(), Err(RectParseError::WrongComponentCount(1)));
+		assert_eq!("1,2,3,4,5".parse::<Rect<f32>>(), Err(RectParseError::WrongComponentCount(5)));
+		assert_eq!("1,2,,4".parse::<Rect<f32>>(), Err(RectParseError::InvalidNumber("".to_string())));
+		assert_eq!("1,2,3,-4".parse::<Rect<u32>>(), Err(RectParseError::InvalidNumber("-4".to_string())));
+	}
 }
\ No newline at end of file
@@ -1,7 +1,9 @@
 use std::cmp::Ordering;
-use std::fmt::Debug;
-use std::ops::Neg;
-use num_traits::{Float};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::ops::{Index, IndexMut, Neg};
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Float, SaturatingAdd, SaturatingSub, Signed, WrappingAdd, WrappingSub};
 use crate::number::Number;
 use crate::impl_ops;
 
@@ -52,6 +54,52 @@ impl<N: Number> Vec2<N> {
 		]))
 	}
 
+	/// Casts the values to `f32`, same as [Self::cast].
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1u8, 2).as_f32(), Vec2::new(1.0, 2.0));
+	/// ```
+	#[inline(always)]
+	pub fn as_f32(self) -> Vec2<f32> {
+		self.cast()
+	}
+
+	/// Casts the values to `f64`, same as [Self::cast].
+	#[inline(always)]
+	pub fn as_f64(self) -> Vec2<f64> {
+		self.cast()
+	}
+
+	/// Casts the values to `i32`, same as [Self::cast].
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.5, -2.5).as_i32(), Vec2::new(1, -2));
+	/// ```
+	#[inline(always)]
+	pub fn as_i32(self) -> Vec2<i32> {
+		self.cast()
+	}
+
+	/// Casts the values to `u32`, same as [Self::cast].
+	#[inline(always)]
+	pub fn as_u32(self) -> Vec2<u32> {
+		self.cast()
+	}
+
+	/// Casts the values to `i64`, same as [Self::cast].
+	#[inline(always)]
+	pub fn as_i64(self) -> Vec2<i64> {
+		self.cast()
+	}
+
+	/// Casts the values to `usize`, same as [Self::cast].
+	#[inline(always)]
+	pub fn as_usize(self) -> Vec2<usize> {
+		self.cast()
+	}
+
 	/// Checks if any of the values match a condition.
 	/// # Examples
 	///
@@ -121,6 +169,36 @@ impl<N: Number> Vec2<N> {
 		])
 	}
 
+	/// Replaces the X value and keeps the original Y value.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1.0, 1.0);
+	/// assert_eq!(v0.with_x(3.0), mathie::Vec2::new(3.0, 1.0))
+	/// ```
+	#[inline(always)]
+	pub fn with_x(self, x: N) -> Vec2<N> {
+		Vec2([
+			x,
+			self.y()
+		])
+	}
+
+	/// Replaces the Y value and keeps the original X value.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1.0, 1.0);
+	/// assert_eq!(v0.with_y(0.0), mathie::Vec2::new(1.0, 0.0))
+	/// ```
+	#[inline(always)]
+	pub fn with_y(self, y: N) -> Vec2<N> {
+		Vec2([
+			self.x(),
+			y
+		])
+	}
+
 	/// Moves the `x` value from the `other` value and keeps its original `y`
 	///
 	/// # Arguments
@@ -226,6 +304,57 @@ impl<N: Number> Vec2<N> {
 		self.y() / self.x()
 	}
 
+	/// Gets the dot product of both of the vectors.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1.0, 2.0);
+	/// assert_eq!(v0.dot(mathie::Vec2::new(3.0, 4.0)), 11.0)
+	/// ```
+	#[inline(always)]
+	pub fn dot(self, other: Vec2<N>) -> N {
+		self.x() * other.x() + self.y() * other.y()
+	}
+
+	/// Gets the squared length of the vector, which is cheaper than the length and works for integers.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(3, 4);
+	/// assert_eq!(v0.length_squared(), 25)
+	/// ```
+	#[inline(always)]
+	pub fn length_squared(self) -> N {
+		self.x() * self.x() + self.y() * self.y()
+	}
+
+	/// Gets the point halfway between both of the vectors.
+	/// For integer vectors the division truncates towards zero.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1.0, 2.0);
+	/// assert_eq!(v0.midpoint(mathie::Vec2::new(2.0, 4.0)), mathie::Vec2::new(1.5, 3.0));
+	/// assert_eq!(mathie::Vec2::new(0, 0).midpoint(mathie::Vec2::new(3, 5)), mathie::Vec2::new(1, 2));
+	/// ```
+	#[inline(always)]
+	pub fn midpoint(self, other: Vec2<N>) -> Vec2<N> {
+		(self + other) / N::from_u8(2).unwrap()
+	}
+
+	/// Gets the 2D cross product of both of the vectors, which is the z component of the 3D cross product.
+	/// It is positive if `other` is counter-clockwise from `self`.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1.0, 0.0);
+	/// assert_eq!(v0.cross(mathie::Vec2::new(0.0, 2.0)), 2.0)
+	/// ```
+	#[inline(always)]
+	pub fn cross(self, other: Vec2<N>) -> N {
+		self.x() * other.y() - self.y() * other.x()
+	}
+
 	/// Returns the X value.
 	#[inline(always)]
 	pub fn x(self) -> N {
@@ -238,6 +367,37 @@ impl<N: Number> Vec2<N> {
 		self.0[1]
 	}
 
+	/// Borrows the values as an array of `[x, y]` without copying.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1.0, 2.0);
+	/// assert_eq!(v0.as_array(), &[1.0, 2.0])
+	/// ```
+	#[inline(always)]
+	pub fn as_array(&self) -> &[N; 2] {
+		&self.0
+	}
+
+	/// Mutably borrows the values as an array of `[x, y]`.
+	/// # Examples
+	///
+	/// ```
+	/// let mut v0 = mathie::Vec2::new(1.0, 2.0);
+	/// v0.as_mut_array()[0] = 3.0;
+	/// assert_eq!(v0, mathie::Vec2::new(3.0, 2.0))
+	/// ```
+	#[inline(always)]
+	pub fn as_mut_array(&mut self) -> &mut [N; 2] {
+		&mut self.0
+	}
+
+	/// Borrows the values as a slice of `[x, y]` without copying.
+	#[inline(always)]
+	pub fn as_slice(&self) -> &[N] {
+		&self.0
+	}
+
 	/// Does nothing.
 	#[inline(always)]
 	pub fn xy(self) -> Vec2<N> {
@@ -252,6 +412,42 @@ impl<N: Number> Vec2<N> {
 			self.x(),
 		])
 	}
+
+	/// Returns X as both of the values.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1, 2);
+	/// assert_eq!(v0.xx(), mathie::Vec2::new(1, 1))
+	/// ```
+	#[inline(always)]
+	pub fn xx(self) -> Vec2<N> {
+		Vec2::split(self.x())
+	}
+
+	/// Returns Y as both of the values.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1, 2);
+	/// assert_eq!(v0.yy(), mathie::Vec2::new(2, 2))
+	/// ```
+	#[inline(always)]
+	pub fn yy(self) -> Vec2<N> {
+		Vec2::split(self.y())
+	}
+
+	/// Mirrors the vector across the main diagonal (`y = x`), the same as [Self::yx].
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1, 2);
+	/// assert_eq!(v0.reflect_diagonal(), mathie::Vec2::new(2, 1))
+	/// ```
+	#[inline(always)]
+	pub fn reflect_diagonal(self) -> Vec2<N> {
+		self.yx()
+	}
 }
 
 impl<N: Number + Ord> Vec2<N> {
@@ -314,113 +510,903 @@ impl<N: Number + Ord> Vec2<N> {
 			]
 		)
 	}
-}
 
-impl<F: Number + Float> Vec2<F> {
-	/// Gets the normalized vector from this vector. Meaning a vector the length of 1
+	/// Clamps each coordinate of the vector into the range of the coordinates of `min` and `max`.
 	/// # Examples
+	///
 	/// ```
-	/// use mathie::Vec2;
-	/// let value = Vec2::new(4.0, 3.0);
-	/// assert_eq!(value.norm(), Vec2::new(4.0 / 5.0, 3.0 / 5.0));
-	/// assert_eq!(value.norm().hypot(), 1.0);
+	/// let v0 = mathie::Vec2::new(-1, 5);
+	/// assert_eq!(v0.clamp(mathie::Vec2::new(0, 0), mathie::Vec2::new(4, 4)), mathie::Vec2::new(0, 4))
+	/// ```
+	#[inline(always)]
+	pub fn clamp(self, min: Vec2<N>, max: Vec2<N>) -> Vec2<N> {
+		self.max(min).min(max)
+	}
+}
+
+impl<N: Number + Neg<Output = N>> Vec2<N> {
+	/// Rotates the vector 90° counter-clockwise, returning `(-y, x)`.
+	/// # Examples
 	///
-	/// let value = Vec2::new(69.0, 420.0);
-	/// assert_eq!(value.norm().hypot(), 1.0);
+	/// ```
+	/// let v0 = mathie::Vec2::new(1, 2);
+	/// assert_eq!(v0.perp_ccw(), mathie::Vec2::new(-2, 1))
 	/// ```
 	#[inline(always)]
-	pub fn norm(self) -> Vec2<F> {
-		let hypot = self.hypot();
+	pub fn perp_ccw(self) -> Vec2<N> {
 		Vec2([
-			self.x() / hypot,
-			self.y() / hypot,
+			-self.y(),
+			self.x(),
 		])
 	}
 
-
-	/// Linearly interpolated the value of `self` and `other` by the value `t` where 0 is self and 1 is other.
-	/// # Arguments
-	///
-	/// * `other`: The target value.
-	/// * `t`:  A value which says where the value should be.
-	///
-	/// returns: Vec2D<N, U>
-	///
+	/// Mirrors the vector across the x axis, returning `(x, -y)`.
 	/// # Examples
+	///
 	/// ```
-	/// let v0 = mathie::Vec2::new(1.0, 1.0);
-	/// let other = mathie::Vec2::new(2.0, 2.0);
-	/// assert_eq!(v0.lerp(other, 0.0), v0);
-	/// assert_eq!(v0.lerp(other, 1.0), other);
-	/// assert_eq!(v0.lerp(other, 0.5), mathie::Vec2::new(1.5, 1.5));
+	/// let v0 = mathie::Vec2::new(1, 2);
+	/// assert_eq!(v0.reflect_x(), mathie::Vec2::new(1, -2))
 	/// ```
 	#[inline(always)]
-	pub fn lerp(self, other: Vec2<F>, t: F) -> Vec2<F> {
+	pub fn reflect_x(self) -> Vec2<N> {
 		Vec2([
-			self.x() + ((other.x() - self.x()) * t),
-			self.y() + ((other.y() - self.y()) * t),
+			self.x(),
+			-self.y(),
 		])
 	}
 
-	/// The same as [Self::min_val] but for floating-point numbers.
+	/// Mirrors the vector across the y axis, returning `(-x, y)`.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1, 2);
+	/// assert_eq!(v0.reflect_y(), mathie::Vec2::new(-1, 2))
+	/// ```
 	#[inline(always)]
-	pub fn minf_val(self) -> F {
-		F::min(self.x(), self.y())
+	pub fn reflect_y(self) -> Vec2<N> {
+		Vec2([
+			-self.x(),
+			self.y(),
+		])
 	}
 
-	/// The same as [Self::max_val] but for floating-point numbers.
+	/// Rotates the vector 90° clockwise, returning `(y, -x)`.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1, 2);
+	/// assert_eq!(v0.perp_cw(), mathie::Vec2::new(2, -1))
+	/// ```
 	#[inline(always)]
-	pub fn maxf_val(self) -> F {
-		F::max(self.x(), self.y())
+	pub fn perp_cw(self) -> Vec2<N> {
+		Vec2([
+			self.y(),
+			-self.x(),
+		])
 	}
 
-	/// The same as [Self::min] but for floating-point numbers.
+	/// Rotates the vector by a quarter turn counter-clockwise without any trigonometry, same as [Self::perp_ccw].
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1, 2);
+	/// assert_eq!(v0.rotate90_ccw(), mathie::Vec2::new(-2, 1))
+	/// ```
 	#[inline(always)]
-	pub fn minf(self, other: Vec2<F>) -> Vec2<F>  {
-		Vec2(
-			[
-				self.x().min(other.x()),
-				self.y().min(other.y()),
-			]
-		)
+	pub fn rotate90_ccw(self) -> Vec2<N> {
+		self.perp_ccw()
 	}
 
-	/// The same as [Self::max] but for floating-point numbers.
+	/// Rotates the vector by a quarter turn clockwise without any trigonometry, same as [Self::perp_cw].
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1, 2);
+	/// assert_eq!(v0.rotate90_cw(), mathie::Vec2::new(2, -1))
+	/// ```
 	#[inline(always)]
-	pub fn maxf(self, other: Vec2<F>) -> Vec2<F> {
-		Vec2(
-			[
-				self.x().max(other.x()),
-				self.y().max(other.y()),
-			]
-		)
+	pub fn rotate90_cw(self) -> Vec2<N> {
+		self.perp_cw()
 	}
 
-	/// Gets the hypotenuse of the vector. In other terms the length.
+	/// Rotates the vector by a half turn, returning `(-x, -y)`.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(1, 2);
+	/// assert_eq!(v0.rotate180(), mathie::Vec2::new(-1, -2))
+	/// ```
 	#[inline(always)]
-	pub fn hypot(self) -> F {
-		F::hypot(self.x(), self.y())
+	pub fn rotate180(self) -> Vec2<N> {
+		Vec2([
+			-self.x(),
+			-self.y(),
+		])
 	}
-}
 
-impl<N: Number> From<[N; 2]> for Vec2<N> {
+	/// Gets the vector triple product `(a × b) × c`, expanded as `b * (a · c) - a * (b · c)`.
+	/// This is commonly used in GJK to get the direction perpendicular to an edge.
+	/// # Examples
+	///
+	/// ```
+	/// use mathie::Vec2;
+	/// let a = Vec2::new(2, 0);
+	/// let b = Vec2::new(1, 3);
+	/// let perpendicular = Vec2::triple_product(a, b, a);
+	/// assert_eq!(perpendicular, Vec2::new(0, 12));
+	/// assert_eq!(perpendicular.dot(a), 0);
+	/// ```
 	#[inline(always)]
-	fn from([x, y]: [N; 2]) -> Self {
-		Vec2::new(x, y)
+	pub fn triple_product(a: Vec2<N>, b: Vec2<N>, c: Vec2<N>) -> Vec2<N> {
+		b * a.dot(c) - a * b.dot(c)
 	}
-}
 
-impl<N: Number> From<(N, N)> for Vec2<N> {
+	/// Gets the turn direction of the three ordered points `a`, `b` and `c`
+	/// from the sign of `(b - a).cross(c - a)`.
+	///
+	/// returns: Ordering `Greater` for a counter-clockwise turn, `Less` for a clockwise turn and `Equal` if they are collinear.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::cmp::Ordering;
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::orientation(Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, 2)), Ordering::Greater);
+	/// assert_eq!(Vec2::orientation(Vec2::new(0, 0), Vec2::new(2, 0), Vec2::new(2, -2)), Ordering::Less);
+	/// assert_eq!(Vec2::orientation(Vec2::new(0, 0), Vec2::new(1, 1), Vec2::new(3, 3)), Ordering::Equal);
+	/// ```
 	#[inline(always)]
-	fn from((x, y): (N, N)) -> Self {
-		Vec2::new(x, y)
+	pub fn orientation(a: Vec2<N>, b: Vec2<N>, c: Vec2<N>) -> Ordering {
+		(b - a).cross(c - a).partial_cmp(&N::zero()).unwrap_or(Ordering::Equal)
 	}
 }
 
-impl<N: Number> From<Vec2<N>> for [N; 2] {
+impl<N: Number + Signed> Vec2<N> {
+	/// Gets the absolute value of both of the values.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(-3.0, 4.0);
+	/// assert_eq!(v0.abs(), mathie::Vec2::new(3.0, 4.0))
+	/// ```
 	#[inline(always)]
-	fn from(vec: Vec2<N>) -> Self {
-		vec.0
+	pub fn abs(self) -> Vec2<N> {
+		Vec2([
+			self.x().abs(),
+			self.y().abs(),
+		])
+	}
+
+	/// Gets the sign of both of the values, see [Signed::signum].
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec2::new(-3, 0);
+	/// assert_eq!(v0.signum(), mathie::Vec2::new(-1, 0))
+	/// ```
+	#[inline(always)]
+	pub fn signum(self) -> Vec2<N> {
+		Vec2([
+			self.x().signum(),
+			self.y().signum(),
+		])
+	}
+
+	/// Gets the manhattan (L1) distance between both of the vectors, `|dx| + |dy|`.
+	/// This is the A* heuristic for grids which only allow orthogonal movement.
+	/// # Examples
+	///
+	/// ```
+	/// let start = mathie::Vec2::new(0, 0);
+	/// let goal = mathie::Vec2::new(3, -4);
+	/// assert_eq!(start.manhattan_distance(goal), 7)
+	/// ```
+	#[inline(always)]
+	pub fn manhattan_distance(self, other: Vec2<N>) -> N {
+		(self - other).abs().add_xy()
+	}
+
+	/// Gets the chebyshev (L∞) distance between both of the vectors, `max(|dx|, |dy|)`.
+	/// This is the A* heuristic for grids which also allow diagonal movement.
+	/// # Examples
+	///
+	/// ```
+	/// let start = mathie::Vec2::new(0, 0);
+	/// let goal = mathie::Vec2::new(3, -4);
+	/// assert_eq!(start.chebyshev_distance(goal), 4)
+	/// ```
+	#[inline(always)]
+	pub fn chebyshev_distance(self, other: Vec2<N>) -> N {
+		let delta = (self - other).abs();
+		if delta.x() > delta.y() {
+			delta.x()
+		} else {
+			delta.y()
+		}
+	}
+}
+
+impl<N: Number + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv> Vec2<N> {
+	/// Adds the vectors per value, returning None if any of the values overflowed.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1, 2).checked_add(Vec2::new(3, 4)), Some(Vec2::new(4, 6)));
+	/// assert_eq!(Vec2::new(i32::MAX, 0).checked_add(Vec2::new(1, 0)), None);
+	/// ```
+	#[inline(always)]
+	pub fn checked_add(self, other: Vec2<N>) -> Option<Vec2<N>> {
+		Some(Vec2([
+			self.x().checked_add(&other.x())?,
+			self.y().checked_add(&other.y())?,
+		]))
+	}
+
+	/// Subtracts the vectors per value, returning None if any of the values overflowed.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(4, 6).checked_sub(Vec2::new(3, 4)), Some(Vec2::new(1, 2)));
+	/// assert_eq!(Vec2::new(0u32, 5).checked_sub(Vec2::new(1, 0)), None);
+	/// ```
+	#[inline(always)]
+	pub fn checked_sub(self, other: Vec2<N>) -> Option<Vec2<N>> {
+		Some(Vec2([
+			self.x().checked_sub(&other.x())?,
+			self.y().checked_sub(&other.y())?,
+		]))
+	}
+
+	/// Multiplies the vectors per value, returning None if any of the values overflowed.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(2, 3).checked_mul(Vec2::new(4, 5)), Some(Vec2::new(8, 15)));
+	/// assert_eq!(Vec2::new(2, i32::MAX).checked_mul(Vec2::new(2, 2)), None);
+	/// ```
+	#[inline(always)]
+	pub fn checked_mul(self, other: Vec2<N>) -> Option<Vec2<N>> {
+		Some(Vec2([
+			self.x().checked_mul(&other.x())?,
+			self.y().checked_mul(&other.y())?,
+		]))
+	}
+
+	/// Divides the vectors per value, returning None if any of the values overflowed or divided by zero.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(8, 15).checked_div(Vec2::new(4, 5)), Some(Vec2::new(2, 3)));
+	/// assert_eq!(Vec2::new(1, 2).checked_div(Vec2::new(1, 0)), None);
+	/// ```
+	#[inline(always)]
+	pub fn checked_div(self, other: Vec2<N>) -> Option<Vec2<N>> {
+		Some(Vec2([
+			self.x().checked_div(&other.x())?,
+			self.y().checked_div(&other.y())?,
+		]))
+	}
+}
+
+impl<N: Number + SaturatingAdd + SaturatingSub> Vec2<N> {
+	/// Adds the vectors per value, clamping at the bounds of the number type instead of overflowing.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(250u8, 1).saturating_add(Vec2::new(10, 1)), Vec2::new(255, 2));
+	/// ```
+	#[inline(always)]
+	pub fn saturating_add(self, other: Vec2<N>) -> Vec2<N> {
+		Vec2([
+			self.x().saturating_add(&other.x()),
+			self.y().saturating_add(&other.y()),
+		])
+	}
+
+	/// Subtracts the vectors per value, clamping at the bounds of the number type instead of overflowing.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(5u8, 1).saturating_sub(Vec2::new(10, 1)), Vec2::new(0, 0));
+	/// ```
+	#[inline(always)]
+	pub fn saturating_sub(self, other: Vec2<N>) -> Vec2<N> {
+		Vec2([
+			self.x().saturating_sub(&other.x()),
+			self.y().saturating_sub(&other.y()),
+		])
+	}
+}
+
+impl<N: Number + WrappingAdd + WrappingSub> Vec2<N> {
+	/// Adds the vectors per value, wrapping around at the bounds of the number type.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(250u8, 1).wrapping_add(Vec2::new(10, 1)), Vec2::new(4, 2));
+	/// ```
+	#[inline(always)]
+	pub fn wrapping_add(self, other: Vec2<N>) -> Vec2<N> {
+		Vec2([
+			self.x().wrapping_add(&other.x()),
+			self.y().wrapping_add(&other.y()),
+		])
+	}
+
+	/// Subtracts the vectors per value, wrapping around at the bounds of the number type.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(5u8, 1).wrapping_sub(Vec2::new(10, 1)), Vec2::new(251, 0));
+	/// ```
+	#[inline(always)]
+	pub fn wrapping_sub(self, other: Vec2<N>) -> Vec2<N> {
+		Vec2([
+			self.x().wrapping_sub(&other.x()),
+			self.y().wrapping_sub(&other.y()),
+		])
+	}
+}
+
+impl<F: Number + Float> Vec2<F> {
+	/// Gets the normalized vector from this vector. Meaning a vector the length of 1
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// let value = Vec2::new(4.0, 3.0);
+	/// assert_eq!(value.norm(), Vec2::new(4.0 / 5.0, 3.0 / 5.0));
+	/// assert_eq!(value.norm().hypot(), 1.0);
+	///
+	/// let value = Vec2::new(69.0, 420.0);
+	/// assert_eq!(value.norm().hypot(), 1.0);
+	/// ```
+	#[inline(always)]
+	pub fn norm(self) -> Vec2<F> {
+		let hypot = self.hypot();
+		Vec2([
+			self.x() / hypot,
+			self.y() / hypot,
+		])
+	}
+
+
+	/// Linearly interpolated the value of `self` and `other` by the value `t` where 0 is self and 1 is other.
+	/// # Arguments
+	///
+	/// * `other`: The target value.
+	/// * `t`:  A value which says where the value should be.
+	///
+	/// returns: Vec2D<N, U>
+	///
+	/// # Examples
+	/// ```
+	/// let v0 = mathie::Vec2::new(1.0, 1.0);
+	/// let other = mathie::Vec2::new(2.0, 2.0);
+	/// assert_eq!(v0.lerp(other, 0.0), v0);
+	/// assert_eq!(v0.lerp(other, 1.0), other);
+	/// assert_eq!(v0.lerp(other, 0.5), mathie::Vec2::new(1.5, 1.5));
+	/// ```
+	#[inline(always)]
+	pub fn lerp(self, other: Vec2<F>, t: F) -> Vec2<F> {
+		Vec2([
+			self.x() + ((other.x() - self.x()) * t),
+			self.y() + ((other.y() - self.y()) * t),
+		])
+	}
+
+	/// Moves towards `target` with a critically damped spring, giving a smooth follow motion without overshooting.
+	///
+	/// # Arguments
+	///
+	/// * `target`: The position to move towards.
+	/// * `velocity`: The current velocity, this is updated in place and should be kept between calls.
+	/// * `smooth_time`: Roughly the time it takes to reach the target.
+	/// * `dt`: The time since the last call.
+	///
+	/// returns: Vec2<F> The new position.
+	#[inline(always)]
+	pub fn smooth_damp(self, target: Vec2<F>, velocity: &mut Vec2<F>, smooth_time: F, dt: F) -> Vec2<F> {
+		let smooth_time = smooth_time.max(F::from_f32(0.0001).unwrap());
+		let omega = F::from_u8(2).unwrap() / smooth_time;
+		let x = omega * dt;
+		let exp = F::one() / (F::one() + x + F::from_f32(0.48).unwrap() * x * x + F::from_f32(0.235).unwrap() * x * x * x);
+
+		let change = self - target;
+		let temp = (*velocity + change * omega) * dt;
+		*velocity = (*velocity - temp * omega) * exp;
+		let out = target + (change + temp) * exp;
+
+		// Stop at the target instead of overshooting it.
+		if (target - self).dot(out - target) > F::zero() {
+			*velocity = Vec2::zero();
+			return target;
+		}
+
+		out
+	}
+
+	/// The same as [Self::min_val] but for floating-point numbers.
+	#[inline(always)]
+	pub fn minf_val(self) -> F {
+		F::min(self.x(), self.y())
+	}
+
+	/// The same as [Self::max_val] but for floating-point numbers.
+	#[inline(always)]
+	pub fn maxf_val(self) -> F {
+		F::max(self.x(), self.y())
+	}
+
+	/// The same as [Self::min] but for floating-point numbers.
+	#[inline(always)]
+	pub fn minf(self, other: Vec2<F>) -> Vec2<F>  {
+		Vec2(
+			[
+				self.x().min(other.x()),
+				self.y().min(other.y()),
+			]
+		)
+	}
+
+	/// The same as [Self::max] but for floating-point numbers.
+	#[inline(always)]
+	pub fn maxf(self, other: Vec2<F>) -> Vec2<F> {
+		Vec2(
+			[
+				self.x().max(other.x()),
+				self.y().max(other.y()),
+			]
+		)
+	}
+
+	/// The same as [Self::clamp] but for floating-point numbers.
+	#[inline(always)]
+	pub fn clampf(self, min: Vec2<F>, max: Vec2<F>) -> Vec2<F> {
+		self.maxf(min).minf(max)
+	}
+
+	/// Rounds both of the values down.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.4, -1.6).floor(), Vec2::new(1.0, -2.0));
+	/// ```
+	#[inline(always)]
+	pub fn floor(self) -> Vec2<F> {
+		self.map(F::floor)
+	}
+
+	/// Rounds both of the values up.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.4, -1.6).ceil(), Vec2::new(2.0, -1.0));
+	/// ```
+	#[inline(always)]
+	pub fn ceil(self) -> Vec2<F> {
+		self.map(F::ceil)
+	}
+
+	/// Rounds both of the values to the nearest integer, rounding half-way cases away from zero.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.4, 1.6).round(), Vec2::new(1.0, 2.0));
+	/// assert_eq!(Vec2::new(0.5, -0.5).round(), Vec2::new(1.0, -1.0));
+	/// ```
+	#[inline(always)]
+	pub fn round(self) -> Vec2<F> {
+		self.map(F::round)
+	}
+
+	/// Rounds both of the values towards zero.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.6, -1.6).trunc(), Vec2::new(1.0, -1.0));
+	/// ```
+	#[inline(always)]
+	pub fn trunc(self) -> Vec2<F> {
+		self.map(F::trunc)
+	}
+
+	/// Gets the fractional part of both of the values, the same as `self - self.trunc()`.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.25, 2.75).fract(), Vec2::new(0.25, 0.75));
+	/// assert_eq!(Vec2::new(-1.25, 0.5).fract(), Vec2::new(-0.25, 0.5));
+	/// ```
+	#[inline(always)]
+	pub fn fract(self) -> Vec2<F> {
+		self.map(F::fract)
+	}
+
+	/// Gets the magnitude of each of the values with the sign of the matching value in `sign`.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.0, -2.0).copysign(Vec2::new(-1.0, 1.0)), Vec2::new(-1.0, 2.0));
+	/// ```
+	#[inline(always)]
+	pub fn copysign(self, sign: Vec2<F>) -> Vec2<F> {
+		Vec2([
+			self.x().copysign(sign.x()),
+			self.y().copysign(sign.y()),
+		])
+	}
+
+	/// Gets the reciprocal `(1 / x, 1 / y)` of both values, useful for inverting a scale.
+	/// A component which is zero becomes infinity, use [Self::try_recip] to catch that.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(2.0, 0.5).recip(), Vec2::new(0.5, 2.0));
+	/// assert_eq!(Vec2::new(0.0, 1.0).recip(), Vec2::new(f64::INFINITY, 1.0));
+	/// ```
+	#[inline(always)]
+	pub fn recip(self) -> Vec2<F> {
+		self.map(F::recip)
+	}
+
+	/// The same as [Self::recip] but returns None if either of the values is zero.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(2.0, 0.5).try_recip(), Some(Vec2::new(0.5, 2.0)));
+	/// assert_eq!(Vec2::new(0.0, 1.0).try_recip(), None);
+	/// ```
+	#[inline(always)]
+	pub fn try_recip(self) -> Option<Vec2<F>> {
+		if self.any(|v| v == F::zero()) {
+			return None;
+		}
+
+		Some(self.recip())
+	}
+
+	/// Raises both of the values to the floating-point power `n`.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(4.0, 9.0).powf(0.5), Vec2::new(2.0, 3.0));
+	/// ```
+	#[inline(always)]
+	pub fn powf(self, n: F) -> Vec2<F> {
+		self.map(|v| v.powf(n))
+	}
+
+	/// Raises both of the values to the integer power `n`.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(2.0, 3.0).powi(2), Vec2::new(4.0, 9.0));
+	/// ```
+	#[inline(always)]
+	pub fn powi(self, n: i32) -> Vec2<F> {
+		self.map(|v| v.powi(n))
+	}
+
+	/// Gets the square root of both of the values.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(4.0, 9.0).sqrt(), Vec2::new(2.0, 3.0));
+	/// ```
+	#[inline(always)]
+	pub fn sqrt(self) -> Vec2<F> {
+		self.map(F::sqrt)
+	}
+
+	/// Gets the hypotenuse of the vector. In other terms the length.
+	#[inline(always)]
+	pub fn hypot(self) -> F {
+		F::hypot(self.x(), self.y())
+	}
+
+	/// Gets the euclidean distance between both of the points.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.0, 1.0).distance(Vec2::new(4.0, 5.0)), 5.0);
+	/// ```
+	#[inline(always)]
+	pub fn distance(self, other: Vec2<F>) -> F {
+		(other - self).hypot()
+	}
+
+	/// Scales the vector so that its length is within `min` and `max`, keeping its direction.
+	/// A zero vector has no direction and is returned unchanged.
+	///
+	/// # Arguments
+	///
+	/// * `min`: The minimum length of the vector.
+	/// * `max`: The maximum length of the vector.
+	///
+	/// returns: Vec2<F>
+	///
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(0.3, 0.4).clamp_length(1.0, 10.0), Vec2::new(0.6, 0.8));
+	/// assert_eq!(Vec2::new(3.0, 4.0).clamp_length(1.0, 10.0), Vec2::new(3.0, 4.0));
+	/// assert_eq!(Vec2::new(30.0, 40.0).clamp_length(1.0, 10.0), Vec2::new(6.0, 8.0));
+	/// assert_eq!(Vec2::zero().clamp_length(1.0, 10.0), Vec2::zero());
+	/// ```
+	#[inline(always)]
+	pub fn clamp_length(self, min: F, max: F) -> Vec2<F> {
+		let length = self.hypot();
+		if length == F::zero() {
+			return self;
+		}
+
+		let clamped = length.max(min).min(max);
+		if clamped == length {
+			return self;
+		}
+
+		self * (clamped / length)
+	}
+
+	/// Gets the angle of the vector from the positive X axis in radians, in the range `(-π, π]`.
+	/// Together with [Self::hypot] this is the polar form of the vector.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.0, 0.0).angle(), 0.0);
+	/// assert_eq!(Vec2::new(0.0, 1.0).angle(), std::f64::consts::FRAC_PI_2);
+	/// assert_eq!(Vec2::new(-1.0, 0.0).angle(), std::f64::consts::PI);
+	/// ```
+	#[inline(always)]
+	pub fn angle(self) -> F {
+		F::atan2(self.y(), self.x())
+	}
+
+	/// Creates a unit vector pointing in the direction of `radians` from the positive X axis.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::from_angle(0.0), Vec2::new(1.0, 0.0));
+	/// assert!((Vec2::from_angle(std::f64::consts::FRAC_PI_2) - Vec2::new(0.0, 1.0)).hypot() < 1e-10);
+	/// ```
+	#[inline(always)]
+	pub fn from_angle(radians: F) -> Vec2<F> {
+		Vec2([
+			F::cos(radians),
+			F::sin(radians),
+		])
+	}
+
+	/// Creates a vector from its polar form, the inverse of [Self::angle] and [Self::hypot].
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::from_polar(0.0, 2.0), Vec2::new(2.0, 0.0));
+	/// ```
+	#[inline(always)]
+	pub fn from_polar(radians: F, length: F) -> Vec2<F> {
+		Vec2::from_angle(radians) * length
+	}
+
+	/// Gets the perpendicular bisector of the points `a` and `b` as a point on the line and its direction.
+	/// If the points are the same the direction is NaN as there is no defined bisector.
+	///
+	/// returns: (Vec2<F>, Vec2<F>) The midpoint and the unit direction, `(b - a)` rotated counter-clockwise.
+	///
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// let (point, direction) = Vec2::perpendicular_bisector(Vec2::new(0.0, 1.0), Vec2::new(4.0, 1.0));
+	/// assert_eq!(point, Vec2::new(2.0, 1.0));
+	/// assert_eq!(direction, Vec2::new(0.0, 1.0));
+	/// ```
+	#[inline(always)]
+	pub fn perpendicular_bisector(a: Vec2<F>, b: Vec2<F>) -> (Vec2<F>, Vec2<F>) {
+		(a.midpoint(b), (b - a).perp_ccw().norm())
+	}
+
+	/// Gets the normalized vector and its counter-clockwise perpendicular, both unit length.
+	/// A zero vector has no direction, so it returns the standard axes `(1, 0)` and `(0, 1)` instead.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// let (tangent, normal) = Vec2::new(3.0f64, 4.0).orthonormal_basis();
+	/// assert_eq!(tangent.dot(normal), 0.0);
+	/// assert!((tangent.hypot() - 1.0).abs() < 1e-10);
+	/// assert!((normal.hypot() - 1.0).abs() < 1e-10);
+	/// assert_eq!(Vec2::zero().orthonormal_basis(), (Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)));
+	/// ```
+	#[inline(always)]
+	pub fn orthonormal_basis(self) -> (Vec2<F>, Vec2<F>) {
+		if self.x() == F::zero() && self.y() == F::zero() {
+			return (Vec2::new(F::one(), F::zero()), Vec2::new(F::zero(), F::one()));
+		}
+
+		let norm = self.norm();
+		(norm, norm.perp_ccw())
+	}
+
+	/// Projects this vector onto `other`.
+	/// If `other` has a length of zero this returns a zero vector instead of NaN.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(2.0, 3.0).project_onto(Vec2::new(2.0, 0.0)), Vec2::new(2.0, 0.0));
+	/// assert_eq!(Vec2::new(2.0, 3.0).project_onto(Vec2::zero()), Vec2::zero());
+	/// ```
+	#[inline(always)]
+	pub fn project_onto(self, other: Vec2<F>) -> Vec2<F> {
+		let length_squared = other.length_squared();
+		if length_squared == F::zero() {
+			return Vec2::zero();
+		}
+
+		other * (self.dot(other) / length_squared)
+	}
+
+	/// Gets the part of this vector which is perpendicular to `other`, the remainder of [Self::project_onto].
+	/// If `other` has a length of zero this returns `self`.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(2.0, 3.0).reject_from(Vec2::new(2.0, 0.0)), Vec2::new(0.0, 3.0));
+	/// assert_eq!(Vec2::new(2.0, 3.0).reject_from(Vec2::zero()), Vec2::new(2.0, 3.0));
+	/// ```
+	#[inline(always)]
+	pub fn reject_from(self, other: Vec2<F>) -> Vec2<F> {
+		self - self.project_onto(other)
+	}
+
+	/// Splits the vector into the component along the surface `normal` and the component tangential to the surface.
+	/// The `normal` is expected to be unit length.
+	///
+	/// returns: (Vec2<F>, Vec2<F>) The normal component and the tangential component.
+	///
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// let (normal, tangential) = Vec2::new(1.0, -1.0).decompose(Vec2::new(0.0, 1.0));
+	/// assert_eq!(normal, Vec2::new(0.0, -1.0));
+	/// assert_eq!(tangential, Vec2::new(1.0, 0.0));
+	/// ```
+	#[inline(always)]
+	pub fn decompose(self, normal: Vec2<F>) -> (Vec2<F>, Vec2<F>) {
+		let normal_component = self.project_onto(normal);
+		(normal_component, self - normal_component)
+	}
+
+	/// Reflects the vector off a surface with the `normal`.
+	/// The `normal` is expected to be unit length, otherwise the result is scaled incorrectly.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.0, -1.0).reflect(Vec2::new(0.0, 1.0)), Vec2::new(1.0, 1.0));
+	/// ```
+	#[inline(always)]
+	pub fn reflect(self, normal: Vec2<F>) -> Vec2<F> {
+		self - normal * (F::from_u8(2).unwrap() * self.dot(normal))
+	}
+
+	/// Same as [Self::reflect] but loses energy along the `normal`.
+	/// The `normal` is expected to be unit length.
+	///
+	/// # Arguments
+	///
+	/// * `normal`: The normal of the surface.
+	/// * `restitution`: How much of the normal component is kept, 0 fully absorbs it and 1 is perfectly elastic.
+	///
+	/// returns: Vec2<F>
+	///
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// let v0 = Vec2::new(1.0, -1.0);
+	/// assert_eq!(v0.reflect_damped(Vec2::new(0.0, 1.0), 1.0), v0.reflect(Vec2::new(0.0, 1.0)));
+	/// assert_eq!(v0.reflect_damped(Vec2::new(0.0, 1.0), 0.5), Vec2::new(1.0, 0.5));
+	/// assert_eq!(v0.reflect_damped(Vec2::new(0.0, 1.0), 0.0), Vec2::new(1.0, 0.0));
+	/// ```
+	#[inline(always)]
+	pub fn reflect_damped(self, normal: Vec2<F>, restitution: F) -> Vec2<F> {
+		let (normal_component, tangential_component) = self.decompose(normal);
+		tangential_component - normal_component * restitution
+	}
+
+	/// Rotates the vector counter-clockwise by `radians`.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// let rotated = Vec2::new(1.0, 0.0).rotate(std::f64::consts::FRAC_PI_2);
+	/// assert!((rotated - Vec2::new(0.0, 1.0)).hypot() < 1e-10);
+	/// ```
+	#[inline(always)]
+	pub fn rotate(self, radians: F) -> Vec2<F> {
+		let (sin, cos) = (F::sin(radians), F::cos(radians));
+		Vec2([
+			self.x() * cos - self.y() * sin,
+			self.x() * sin + self.y() * cos,
+		])
+	}
+
+	/// Gets the unsigned angle in radians between the directions of both vectors, in the range `[0, π]`.
+	/// This uses `atan2(cross, dot)` which stays accurate for nearly parallel vectors, unlike `acos`.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// assert_eq!(Vec2::new(1.0, 0.0).angle_between(Vec2::new(0.0, 2.0)), std::f64::consts::FRAC_PI_2);
+	/// assert_eq!(Vec2::new(1.0, 0.0).angle_between(Vec2::new(0.0, -2.0)), std::f64::consts::FRAC_PI_2);
+	/// ```
+	#[inline(always)]
+	pub fn angle_between(self, other: Vec2<F>) -> F {
+		F::atan2(self.cross(other), self.dot(other)).abs()
+	}
+
+	/// Gets the signed angle in radians which rotates this vector onto the direction of `other`, in the range `(-π, π]`.
+	/// A positive angle is a counter-clockwise rotation and a negative angle is clockwise,
+	/// so `self.rotate(self.angle_to(other))` points in the same direction as `other`.
+	/// See [Self::angle_between] for the unsigned angle.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// use std::f64::consts::FRAC_PI_2;
+	/// let forward = Vec2::new(1.0, 0.0);
+	/// assert_eq!(forward.angle_to(Vec2::new(0.0, 1.0)), FRAC_PI_2);
+	/// assert_eq!(forward.angle_to(Vec2::new(0.0, -1.0)), -FRAC_PI_2);
+	/// ```
+	#[inline(always)]
+	pub fn angle_to(self, other: Vec2<F>) -> F {
+		F::atan2(self.cross(other), self.dot(other))
+	}
+
+	/// Limits the direction of this vector to within `max_angle` radians of the `axis`, keeping its length.
+	/// If the vector is outside the cone it is rotated onto the nearest edge of the cone.
+	///
+	/// # Arguments
+	///
+	/// * `axis`: The direction in the center of the cone.
+	/// * `max_angle`: The half angle of the cone in radians.
+	///
+	/// returns: Vec2<F>
+	///
+	/// # Examples
+	/// ```
+	/// use mathie::Vec2;
+	/// use std::f64::consts::FRAC_PI_4;
+	/// let axis = Vec2::new(1.0, 0.0);
+	/// let inside = Vec2::new(2.0, 1.0);
+	/// assert_eq!(inside.clamp_to_cone(axis, FRAC_PI_4), inside);
+	///
+	/// let clamped = Vec2::new(0.0, -2.0).clamp_to_cone(axis, FRAC_PI_4);
+	/// assert!((clamped - Vec2::new(2.0f64.sqrt(), -2.0f64.sqrt())).hypot() < 1e-10);
+	/// ```
+	#[inline(always)]
+	pub fn clamp_to_cone(self, axis: Vec2<F>, max_angle: F) -> Vec2<F> {
+		let angle = axis.angle_to(self);
+		if angle.abs() <= max_angle {
+			return self;
+		}
+
+		self.rotate(max_angle.copysign(angle) - angle)
+	}
+}
+
+impl<N: Number> From<[N; 2]> for Vec2<N> {
+	#[inline(always)]
+	fn from([x, y]: [N; 2]) -> Self {
+		Vec2::new(x, y)
+	}
+}
+
+impl<N: Number> From<(N, N)> for Vec2<N> {
+	#[inline(always)]
+	fn from((x, y): (N, N)) -> Self {
+		Vec2::new(x, y)
+	}
+}
+
+impl<N: Number> From<Vec2<N>> for [N; 2] {
+	#[inline(always)]
+	fn from(vec: Vec2<N>) -> Self {
+		vec.0
 	}
 }
 
@@ -431,6 +1417,131 @@ impl<N: Number> From<Vec2<N>> for (N, N) {
 	}
 }
 
+impl<N: Number> IntoIterator for Vec2<N> {
+	type Item = N;
+	type IntoIter = std::array::IntoIter<N, 2>;
+
+	/// Iterates over the X and then the Y value.
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, N: Number> IntoIterator for &'a Vec2<N> {
+	type Item = &'a N;
+	type IntoIter = std::slice::Iter<'a, N>;
+
+	/// Iterates over references to the X and then the Y value.
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+/// Indexes the values where `0` is X and `1` is Y.
+/// # Panics
+/// Panics if the index is 2 or higher, like slice indexing.
+impl<N: Number> Index<usize> for Vec2<N> {
+	type Output = N;
+
+	#[inline(always)]
+	fn index(&self, index: usize) -> &Self::Output {
+		&self.0[index]
+	}
+}
+
+/// Mutably indexes the values where `0` is X and `1` is Y.
+/// # Panics
+/// Panics if the index is 2 or higher, like slice indexing.
+impl<N: Number> IndexMut<usize> for Vec2<N> {
+	#[inline(always)]
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+		&mut self.0[index]
+	}
+}
+
+impl<N: Number> Default for Vec2<N> {
+	#[inline(always)]
+	fn default() -> Self {
+		Vec2::zero()
+	}
+}
+
+/// Formats the vector as `(x, y)`.
+/// The formatting options like the precision are applied to each of the values.
+/// # Examples
+/// ```
+/// use mathie::Vec2;
+/// assert_eq!(Vec2::new(1, 2).to_string(), "(1, 2)");
+/// assert_eq!(format!("{:.2}", Vec2::new(1.0, 0.5)), "(1.00, 0.50)");
+/// assert_eq!(format!("{:+}", Vec2::new(1, -2)), "(+1, -2)");
+/// ```
+impl<N: Number + Display> Display for Vec2<N> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str("(")?;
+		Display::fmt(&self.x(), f)?;
+		f.write_str(", ")?;
+		Display::fmt(&self.y(), f)?;
+		f.write_str(")")
+	}
+}
+
+/// The error returned when parsing a [Vec2] from a string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Vec2ParseError {
+	/// The string did not contain exactly two values, holds the amount of values found.
+	WrongComponentCount(usize),
+	/// One of the values could not be parsed as a number, holds the value which failed.
+	InvalidNumber(String),
+}
+
+impl Display for Vec2ParseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Vec2ParseError::WrongComponentCount(count) => write!(f, "expected 2 values but found {count}"),
+			Vec2ParseError::InvalidNumber(value) => write!(f, "invalid number \"{value}\""),
+		}
+	}
+}
+
+impl std::error::Error for Vec2ParseError {}
+
+/// Parses the vector from two values separated by a comma or whitespace, like `1.5, 2.0` or `1.5 2.0`.
+/// The values may be wrapped in parentheses, so the output of [Display] can be parsed back.
+/// # Examples
+/// ```
+/// use mathie::{Vec2, Vec2ParseError};
+/// assert_eq!("1.5, 2.0".parse(), Ok(Vec2::new(1.5, 2.0)));
+/// assert_eq!("1.5 2.0".parse(), Ok(Vec2::new(1.5, 2.0)));
+/// assert_eq!("(1, 2)".parse(), Ok(Vec2::new(1, 2)));
+/// assert_eq!("1, 2, 3".parse::<Vec2<i32>>(), Err(Vec2ParseError::WrongComponentCount(3)));
+/// ```
+impl<N: Number + FromStr> FromStr for Vec2<N> {
+	type Err = Vec2ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.trim();
+		let s = s
+			.strip_prefix('(')
+			.and_then(|s| s.strip_suffix(')'))
+			.unwrap_or(s);
+
+		let values: Vec<&str> = if s.contains(',') {
+			s.split(',').map(str::trim).collect()
+		} else {
+			s.split_whitespace().collect()
+		};
+
+		if values.len() != 2 {
+			return Err(Vec2ParseError::WrongComponentCount(values.len()));
+		}
+
+		let parse = |value: &str| value.parse::<N>().map_err(|_| Vec2ParseError::InvalidNumber(value.to_string()));
+		Ok(Vec2([parse(values[0])?, parse(values[1])?]))
+	}
+}
+
 impl<N: Number> PartialEq<Self> for Vec2<N> {
 	#[inline(always)]
 	fn eq(&self, other: &Self) -> bool {
@@ -440,6 +1551,12 @@ impl<N: Number> PartialEq<Self> for Vec2<N> {
 
 impl<N: Number> Eq for Vec2<N> {}
 
+impl<N: Number + Hash> Hash for Vec2<N> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.hash(state);
+	}
+}
+
 impl<N: Number> PartialOrd<Self> for Vec2<N> {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
 		let x = self.x().partial_cmp(&other.x())?;
@@ -517,4 +1634,128 @@ impl<N: Number + Neg<Output = N>> Neg for Vec2<N> {
 			-self.y(),
 		])
 	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::hash_map::DefaultHasher;
+	use super::*;
+
+	fn hash(value: impl Hash) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		value.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	#[test]
+	fn smooth_damp_converges() {
+		let target = Vec2::new(10.0f32, -5.0);
+		let mut position = Vec2::new(0.0f32, 0.0);
+		let mut velocity = Vec2::zero();
+		let mut distance = (target - position).hypot();
+		for _ in 0..200 {
+			position = position.smooth_damp(target, &mut velocity, 0.3, 1.0 / 60.0);
+			let new_distance = (target - position).hypot();
+			assert!(new_distance <= distance);
+			distance = new_distance;
+		}
+
+		assert!(distance < 1e-3);
+		assert!(velocity.hypot() < 1e-2);
+	}
+
+	#[test]
+	fn index() {
+		let mut vec = Vec2::new(1, 2);
+		assert_eq!(vec[0], 1);
+		assert_eq!(vec[1], 2);
+		vec[1] = 5;
+		for axis in 0..2 {
+			vec[axis] += 1;
+		}
+		assert_eq!(vec, Vec2::new(2, 6));
+	}
+
+	#[test]
+	#[should_panic]
+	fn index_out_of_range() {
+		let _ = Vec2::new(1, 2)[2];
+	}
+
+	#[test]
+	fn into_iter() {
+		let vec = Vec2::new(3, 4);
+		assert_eq!(vec.into_iter().collect::<Vec<_>>(), vec![3, 4]);
+		assert_eq!((&vec).into_iter().sum::<i32>(), 7);
+
+		let mut total = 0;
+		for v in vec {
+			total += v;
+		}
+		assert_eq!(total, 7);
+	}
+
+	#[test]
+	fn rotate90_cycle() {
+		let v0 = Vec2::new(3, -7);
+		assert_eq!(v0.rotate90_ccw().rotate90_ccw().rotate90_ccw().rotate90_ccw(), v0);
+		assert_eq!(v0.rotate90_cw().rotate90_cw().rotate90_cw().rotate90_cw(), v0);
+		assert_eq!(v0.rotate90_ccw().rotate90_ccw(), v0.rotate180());
+		assert_eq!(v0.rotate90_cw().rotate90_cw(), v0.rotate180());
+		assert_eq!(v0.rotate180().rotate180(), v0);
+		assert_eq!(v0.rotate90_ccw().rotate90_cw(), v0);
+	}
+
+	#[test]
+	fn parse() {
+		for vec in [Vec2::new(1.5, -2.0), Vec2::new(0.1, 1e20), Vec2::new(f64::MAX, f64::MIN_POSITIVE)] {
+			assert_eq!(vec.to_string().parse::<Vec2<f64>>(), Ok(vec));
+		}
+		assert_eq!(Vec2::new(-3, 4).to_string().parse::<Vec2<i32>>(), Ok(Vec2::new(-3, 4)));
+		assert_eq!("  7\t8 ".parse::<Vec2<u8>>(), Ok(Vec2::new(7, 8)));
+
+		assert_eq!("".parse::<Vec2<f32>>(), Err(Vec2ParseError::WrongComponentCount(0)));
+		assert_eq!("1".parse::<Vec2<f32>>(), Err(Vec2ParseError::WrongComponentCount(1)));
+		assert_eq!("1,,2".parse::<Vec2<f32>>(), Err(Vec2ParseError::WrongComponentCount(3)));
+		assert_eq!("1, x".parse::<Vec2<f32>>(), Err(Vec2ParseError::InvalidNumber("x".to_string())));
+		assert_eq!("1.5, 2".parse::<Vec2<i32>>(), Err(Vec2ParseError::InvalidNumber("1.5".to_string())));
+	}
+
+	#[test]
+	fn checked() {
+		let max = Vec2::new(i32::MAX, i32::MIN);
+		assert_eq!(max.checked_add(Vec2::new(1, 0)), None);
+		assert_eq!(max.checked_add(Vec2::new(0, -1)), None);
+		assert_eq!(max.checked_add(Vec2::new(0, 1)), Some(Vec2::new(i32::MAX, i32::MIN + 1)));
+		assert_eq!(max.checked_sub(Vec2::new(0, 1)), None);
+		assert_eq!(max.checked_sub(Vec2::new(-1, 0)), None);
+		assert_eq!(max.checked_mul(Vec2::new(1, -1)), None);
+		assert_eq!(max.checked_mul(Vec2::new(1, 1)), Some(max));
+		assert_eq!(max.checked_div(Vec2::new(1, -1)), None);
+		assert_eq!(max.checked_div(Vec2::new(0, 1)), None);
+	}
+
+	#[test]
+	fn saturating() {
+		let bounds = Vec2::new(i32::MAX, i32::MIN);
+		assert_eq!(bounds.saturating_add(Vec2::new(1, -1)), bounds);
+		assert_eq!(bounds.saturating_sub(Vec2::new(-1, 1)), bounds);
+		assert_eq!(bounds.saturating_add(Vec2::new(-1, 1)), Vec2::new(i32::MAX - 1, i32::MIN + 1));
+		assert_eq!(Vec2::new(0u32, u32::MAX).saturating_sub(Vec2::new(1, 0)), Vec2::new(0, u32::MAX));
+	}
+
+	#[test]
+	fn wrapping() {
+		let bounds = Vec2::new(i32::MAX, i32::MIN);
+		assert_eq!(bounds.wrapping_add(Vec2::new(1, -1)), Vec2::new(i32::MIN, i32::MAX));
+		assert_eq!(bounds.wrapping_sub(Vec2::new(-1, 1)), Vec2::new(i32::MIN, i32::MAX));
+		assert_eq!(Vec2::new(0u32, u32::MAX).wrapping_sub(Vec2::new(1, 0)), Vec2::new(u32::MAX, u32::MAX));
+		assert_eq!(Vec2::new(0u32, u32::MAX).wrapping_add(Vec2::new(0, 1)), Vec2::new(0, 0));
+	}
+
+	#[test]
+	fn hash_equal() {
+		assert_eq!(hash(Vec2::new(1, 2)), hash(Vec2::new(1, 2)));
+		assert_ne!(hash(Vec2::new(1, 2)), hash(Vec2::new(2, 1)));
+	}
 }
\ No newline at end of file
@@ -0,0 +1,292 @@
+use std::hash::{Hash, Hasher};
+use std::ops::Neg;
+use num_traits::Float;
+use crate::number::Number;
+use crate::impl_ops;
+use crate::Vec2;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Vec3<T: Number>(pub(crate) [T; 3]);
+
+impl<N: Number> Vec3<N> {
+	#[inline(always)]
+	pub const fn new(x: N, y: N, z: N) -> Vec3<N> {
+		Vec3([x, y, z])
+	}
+
+	#[inline(always)]
+	pub const fn split(v: N) -> Vec3<N> {
+		Vec3([v, v, v])
+	}
+
+	#[inline(always)]
+	pub fn zero() -> Vec3<N> {
+		Vec3::split(N::zero())
+	}
+
+	#[inline(always)]
+	pub fn one() -> Vec3<N> {
+		Vec3::split(N::one())
+	}
+
+	/// Tries to cast the values to different number type.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec3;
+	/// assert_eq!(Vec3::<f32>::new(1.5, 2.5, 3.5).cast(), Vec3::<u32>::new(1, 2, 3));
+	/// ```
+	#[inline(always)]
+	pub fn cast<NO: Number>(self) -> Vec3<NO> {
+		self.try_cast().expect("Failed to cast number")
+	}
+
+	/// Same as [Self::cast] but returns None if the cast failed.
+	#[inline(always)]
+	pub fn try_cast<NO: Number>(self) -> Option<Vec3<NO>> {
+		Some(Vec3([
+			NO::from(self.x())?,
+			NO::from(self.y())?,
+			NO::from(self.z())?,
+		]))
+	}
+
+	/// Maps all of the values to the function result.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec3::new(1.0, 2.0, 3.0);
+	/// assert_eq!(v0.map(|v| v * 2.0), mathie::Vec3::new(2.0, 4.0, 6.0))
+	/// ```
+	#[inline(always)]
+	pub fn map<NO: Number>(self, func: impl Fn(N) -> NO) -> Vec3<NO> {
+		Vec3([
+			func(self.x()),
+			func(self.y()),
+			func(self.z()),
+		])
+	}
+
+	/// Gets the dot product of both of the vectors.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec3::new(1.0, 2.0, 3.0);
+	/// assert_eq!(v0.dot(mathie::Vec3::new(4.0, 5.0, 6.0)), 32.0)
+	/// ```
+	#[inline(always)]
+	pub fn dot(self, other: Vec3<N>) -> N {
+		self.x() * other.x() + self.y() * other.y() + self.z() * other.z()
+	}
+
+	/// Gets the cross product of both of the vectors, which is perpendicular to both of them.
+	/// # Examples
+	///
+	/// ```
+	/// use mathie::Vec3;
+	/// assert_eq!(Vec3::new(1, 0, 0).cross(Vec3::new(0, 1, 0)), Vec3::new(0, 0, 1));
+	/// ```
+	#[inline(always)]
+	pub fn cross(self, other: Vec3<N>) -> Vec3<N> {
+		Vec3([
+			self.y() * other.z() - self.z() * other.y(),
+			self.z() * other.x() - self.x() * other.z(),
+			self.x() * other.y() - self.y() * other.x(),
+		])
+	}
+
+	/// Gets the squared length of the vector, which is cheaper than the length and works for integers.
+	#[inline(always)]
+	pub fn length_squared(self) -> N {
+		self.dot(self)
+	}
+
+	/// Returns the X value.
+	#[inline(always)]
+	pub fn x(self) -> N {
+		self.0[0]
+	}
+
+	/// Returns the Y value.
+	#[inline(always)]
+	pub fn y(self) -> N {
+		self.0[1]
+	}
+
+	/// Returns the Z value.
+	#[inline(always)]
+	pub fn z(self) -> N {
+		self.0[2]
+	}
+
+	/// Drops the Z value.
+	/// # Examples
+	///
+	/// ```
+	/// let v0 = mathie::Vec3::new(1, 2, 3);
+	/// assert_eq!(v0.xy(), mathie::Vec2::new(1, 2))
+	/// ```
+	#[inline(always)]
+	pub fn xy(self) -> Vec2<N> {
+		Vec2::new(self.x(), self.y())
+	}
+}
+
+impl<F: Number + Float> Vec3<F> {
+	/// Gets the length of the vector.
+	/// # Examples
+	/// ```
+	/// use mathie::Vec3;
+	/// assert_eq!(Vec3::new(2.0, 3.0, 6.0).length(), 7.0);
+	/// ```
+	#[inline(always)]
+	pub fn length(self) -> F {
+		self.length_squared().sqrt()
+	}
+
+	/// Normalizes the vector so its length is 1.
+	#[inline(always)]
+	pub fn norm(self) -> Vec3<F> {
+		self / self.length()
+	}
+}
+
+impl<N: Number> From<[N; 3]> for Vec3<N> {
+	fn from(value: [N; 3]) -> Self {
+		Vec3(value)
+	}
+}
+
+impl<N: Number> From<(N, N, N)> for Vec3<N> {
+	fn from((x, y, z): (N, N, N)) -> Self {
+		Vec3([x, y, z])
+	}
+}
+
+impl<N: Number> From<Vec3<N>> for [N; 3] {
+	fn from(value: Vec3<N>) -> Self {
+		value.0
+	}
+}
+
+impl<N: Number> From<Vec3<N>> for (N, N, N) {
+	fn from(value: Vec3<N>) -> Self {
+		(value.x(), value.y(), value.z())
+	}
+}
+
+impl<N: Number> Default for Vec3<N> {
+	#[inline(always)]
+	fn default() -> Self {
+		Vec3::zero()
+	}
+}
+
+impl<N: Number> PartialEq<Self> for Vec3<N> {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl<N: Number> Eq for Vec3<N> {}
+
+impl<N: Number + Hash> Hash for Vec3<N> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.hash(state);
+	}
+}
+
+macro_rules! impl_op {
+    ($TRAIT:ident $TRAIT_ASSIGN:ident $METHOD:ident $METHOD_ASSIGN:ident) => {
+        impl<T: Number + $TRAIT> $TRAIT for Vec3<T> {
+            type Output = Vec3<T>;
+
+            fn $METHOD(self, rhs: Self) -> Self::Output {
+                Vec3([
+	                self.x().$METHOD(rhs.x()),
+	                self.y().$METHOD(rhs.y()),
+	                self.z().$METHOD(rhs.z())
+                ])
+            }
+        }
+
+        impl<T: Number + $TRAIT> $TRAIT<T> for Vec3<T> {
+            type Output = Vec3<T>;
+
+            fn $METHOD(self, rhs: T) -> Self::Output {
+                Vec3([self.x().$METHOD(rhs), self.y().$METHOD(rhs), self.z().$METHOD(rhs)])
+            }
+        }
+
+        impl<T: Number + $TRAIT> $TRAIT_ASSIGN<Vec3<T>> for Vec3<T> {
+            fn $METHOD_ASSIGN(&mut self, rhs: Self) {
+                *self = self.$METHOD(rhs);
+            }
+        }
+
+	    impl<T: Number + $TRAIT> $TRAIT_ASSIGN<T> for Vec3<T> {
+            fn $METHOD_ASSIGN(&mut self, rhs: T) {
+                *self = self.$METHOD(rhs);
+            }
+        }
+    };
+}
+
+impl_ops!(impl_op);
+
+impl<N: Number + Neg<Output = N>> Neg for Vec3<N> {
+	type Output = Vec3<N>;
+
+	fn neg(self) -> Self::Output {
+		Vec3([
+			-self.x(),
+			-self.y(),
+			-self.z(),
+		])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ops() {
+		let v0 = Vec3::new(1, 2, 3);
+		assert_eq!(v0 + Vec3::one(), Vec3::new(2, 3, 4));
+		assert_eq!(v0 - 1, Vec3::new(0, 1, 2));
+		assert_eq!(v0 * Vec3::new(2, 3, 4), Vec3::new(2, 6, 12));
+		assert_eq!(v0 / 2, Vec3::new(0, 1, 1));
+		assert_eq!(v0 % 2, Vec3::new(1, 0, 1));
+		assert_eq!(-v0, Vec3::new(-1, -2, -3));
+
+		let mut v1 = v0;
+		v1 += Vec3::split(1);
+		v1 *= 2;
+		assert_eq!(v1, Vec3::new(4, 6, 8));
+	}
+
+	#[test]
+	fn cross() {
+		let a = Vec3::new(1.0, 2.0, 3.0);
+		let b = Vec3::new(-4.0, 0.5, 2.0);
+		let c = a.cross(b);
+		assert_eq!(c.dot(a), 0.0);
+		assert_eq!(c.dot(b), 0.0);
+		assert_eq!(b.cross(a), -c);
+		assert_eq!(a.cross(a), Vec3::zero());
+		assert_eq!(Vec3::new(0, 1, 0).cross(Vec3::new(0, 0, 1)), Vec3::new(1, 0, 0));
+		assert_eq!(Vec3::new(0, 0, 1).cross(Vec3::new(1, 0, 0)), Vec3::new(0, 1, 0));
+	}
+
+	#[test]
+	fn conversions() {
+		let v0 = Vec3::from([1, 2, 3]);
+		assert_eq!(v0, Vec3::from((1, 2, 3)));
+		assert_eq!(<[i32; 3]>::from(v0), [1, 2, 3]);
+		assert_eq!(<(i32, i32, i32)>::from(v0), (1, 2, 3));
+		assert_eq!(v0.xy(), Vec2::new(1, 2));
+		assert_eq!(Vec3::<i32>::default(), Vec3::zero());
+	}
+}